@@ -4,31 +4,48 @@
 
 use abscissa_core::{Command, Runnable};
 use gumdrop::Options;
-use std::path::{Path, PathBuf};
+use std::path::PathBuf;
 
 /// `rustsec-admin update-advisories` subcommand
 #[derive(Command, Debug, Default, Options)]
 pub struct UpdateAdvisoriesCmd {
     #[options(long = "github-actions-output")]
     github_action_output: bool,
-    /// Path to the advisory database
-    #[options(free, help = "filesystem path to the RustSec advisory DB git repo")]
+    /// NVD API key, used to raise the NVD rate limit. Falls back to the
+    /// `NVD_API_KEY` environment variable when not given.
+    #[options(long = "nvd-api-key", help = "NVD API key (or set NVD_API_KEY)")]
+    nvd_api_key: Option<String>,
+    /// GitHub token, used to query the GHSA GraphQL API. Falls back to the
+    /// `GITHUB_TOKEN` environment variable when not given.
+    #[options(long = "github-token", help = "GitHub token (or set GITHUB_TOKEN)")]
+    github_token: Option<String>,
+    /// Paths to the advisory databases. Each may be a single advisory DB git
+    /// repo, or a directory containing several DB checkouts as immediate
+    /// subdirectories (mirroring cargo-deny's advisories layout).
+    #[options(free, help = "filesystem path(s) to the RustSec advisory DB git repo(s)")]
     path: Vec<PathBuf>,
 }
 
 impl Runnable for UpdateAdvisoriesCmd {
     fn run(&self) {
-        let repo_path = match self.path.len() {
-            0 => Path::new("."),
-            1 => self.path[0].as_path(),
-            _ => Self::print_usage_and_exit(&[]),
+        let repo_paths = if self.path.is_empty() {
+            vec![PathBuf::from(".")]
+        } else {
+            self.path.clone()
         };
         let output_mode = if self.github_action_output {
             crate::updater::OutputMode::GithubAction
         } else {
             crate::updater::OutputMode::HumanReadable
         };
+        let nvd_api_key = crate::updater::nvd_api_key(self.nvd_api_key.as_deref());
+        let github_token = crate::ghsa::github_token(self.github_token.as_deref());
 
-        crate::updater::update_advisories(repo_path, output_mode);
+        crate::updater::update_advisories(
+            &repo_paths,
+            output_mode,
+            nvd_api_key.as_deref(),
+            github_token.as_deref(),
+        );
     }
 }
@@ -0,0 +1,130 @@
+//! GitHub Security Advisory (GHSA) lookups via the GitHub GraphQL API.
+
+use serde_json::json;
+use std::env;
+
+const GITHUB_GRAPHQL_URL: &str = "https://api.github.com/graphql";
+
+/// Environment variable holding a GitHub token, used as a fallback for the
+/// `--github-token` CLI flag. A token is required to query the GraphQL API.
+pub const GITHUB_TOKEN_ENV_VAR: &str = "GITHUB_TOKEN";
+
+const SECURITY_ADVISORY_QUERY: &str = r#"
+query($ghsaId: String!) {
+  securityAdvisory(ghsaId: $ghsaId) {
+    cvss {
+      vectorString
+    }
+    withdrawnAt
+    identifiers {
+      type
+      value
+    }
+    cwes(first: 25) {
+      nodes {
+        cweId
+      }
+    }
+    vulnerabilities(first: 25) {
+      nodes {
+        package {
+          ecosystem
+        }
+      }
+    }
+  }
+}
+"#;
+
+/// Structured data about a GitHub Security Advisory, as returned by the
+/// GraphQL `securityAdvisory`/`securityVulnerabilities` API.
+#[derive(Debug, Clone)]
+pub struct GhsaAdvisory {
+    /// CVSS vector reported by GHSA, if any.
+    pub cvss: Option<cvss::v3::Base>,
+
+    /// CVE aliases GHSA associates with this advisory.
+    pub aliases: Vec<String>,
+
+    /// CWE ids (e.g. `"CWE-79"`) GHSA associates with this advisory.
+    pub cwes: Vec<String>,
+
+    /// Package ecosystems (e.g. `"RUST"`, `"NPM"`) affected by this advisory.
+    pub ecosystems: Vec<String>,
+
+    /// Whether GHSA has withdrawn this advisory.
+    pub withdrawn: bool,
+}
+
+impl GhsaAdvisory {
+    /// Does this advisory affect a Rust/crates.io package?
+    pub fn is_rust_advisory(&self) -> bool {
+        self.ecosystems.iter().any(|ecosystem| ecosystem == "RUST")
+    }
+}
+
+/// Read the GitHub token, preferring an explicit CLI flag over the
+/// `GITHUB_TOKEN` environment variable.
+pub fn github_token(flag: Option<&str>) -> Option<String> {
+    flag.map(ToOwned::to_owned)
+        .or_else(|| env::var(GITHUB_TOKEN_ENV_VAR).ok())
+}
+
+/// Fetch a GHSA advisory by id (e.g. `"GHSA-xxxx-xxxx-xxxx"`) from the
+/// GitHub GraphQL API. Returns `Ok(None)` if no such advisory exists.
+pub fn fetch_ghsa_advisory(id: &str, token: &str) -> Result<Option<GhsaAdvisory>, ()> {
+    let response = ureq::post(GITHUB_GRAPHQL_URL)
+        .set("Authorization", &format!("Bearer {token}"))
+        .send_json(json!({
+            "query": SECURITY_ADVISORY_QUERY,
+            "variables": { "ghsaId": id },
+        }))
+        .map_err(|_| ())?;
+
+    let body = response.into_string().map_err(|_| ())?;
+    let data: serde_json::Value = serde_json::from_str(&body).map_err(|_| ())?;
+
+    let advisory = &data["data"]["securityAdvisory"];
+    if advisory.is_null() {
+        return Ok(None);
+    }
+
+    let cvss = advisory["cvss"]["vectorString"]
+        .as_str()
+        .and_then(|s| s.parse().ok());
+
+    let withdrawn = !advisory["withdrawnAt"].is_null();
+
+    let aliases = advisory["identifiers"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter(|identifier| identifier["type"].as_str() == Some("CVE"))
+        .filter_map(|identifier| identifier["value"].as_str())
+        .map(ToOwned::to_owned)
+        .collect();
+
+    let cwes = advisory["cwes"]["nodes"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|node| node["cweId"].as_str())
+        .map(ToOwned::to_owned)
+        .collect();
+
+    let ecosystems = advisory["vulnerabilities"]["nodes"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|node| node["package"]["ecosystem"].as_str())
+        .map(ToOwned::to_owned)
+        .collect();
+
+    Ok(Some(GhsaAdvisory {
+        cvss,
+        aliases,
+        cwes,
+        ecosystems,
+        withdrawn,
+    }))
+}
@@ -1,8 +1,9 @@
 //! RustSec Advisory DB tool to update advisory data
 
+use crate::ghsa::GhsaAdvisory;
 use crate::prelude::*;
-use std::collections::HashSet;
-use std::{path::Path, process::exit, thread::sleep, time::Duration};
+use std::collections::{HashMap, HashSet};
+use std::{env, fs, path::PathBuf, process::exit, thread::sleep, time::Duration};
 use url::Url;
 
 // Goals:
@@ -18,15 +19,37 @@ use url::Url;
 //
 // Read current advisories
 // Check for updated data from NVD (cvss, cwe, aliases)
-// Check for inconsistencies from NVD
-// TODO GHSA
+// Check for inconsistencies from NVD and GHSA
 
 // Open PR for changes, issues for problems and potential advisories
 
-const NVD_API_URL: &str = "https://services.nvd.nist.gov/rest/json/cve/1.0";
-// minimal sleep between call to the API to comply wit rate-limiting
-// value found by trial and error
-const NVD_API_SLEEP_MS: u64 = 200;
+const NVD_API_URL: &str = "https://services.nvd.nist.gov/rest/json/cves/2.0";
+
+/// Environment variable holding an NVD API key, used as a fallback for the
+/// `--nvd-api-key` CLI flag.
+pub const NVD_API_KEY_ENV_VAR: &str = "NVD_API_KEY";
+
+// NVD returns results a page at a time; fetching the whole database up
+// front in a handful of paged requests is much faster than one serial
+// request per advisory.
+const NVD_RESULTS_PER_PAGE: u32 = 2_000;
+
+// Minimal sleep between calls to the API to comply with rate-limiting.
+// NVD allows roughly 5 requests per 30s unauthenticated, and 50 requests
+// per 30s with an API key; values found by trial and error.
+const NVD_API_SLEEP_MS: u64 = 6_000;
+const NVD_API_SLEEP_MS_WITH_KEY: u64 = 600;
+
+// How many times to retry a request that NVD rate-limited (HTTP 403/429)
+// before giving up.
+const NVD_MAX_RETRIES: u32 = 5;
+
+// How many *consecutive* page failures (after `get_with_backoff`'s own
+// retries are exhausted, or a malformed response body) `fetch_all_cves`
+// tolerates before giving up on the whole crawl. An isolated bad page
+// shouldn't lose the rest of the database; a persistent failure should
+// still abort rather than loop forever.
+const NVD_MAX_CONSECUTIVE_PAGE_FAILURES: u32 = 3;
 
 /// What sort of output should be generated on stdout.
 #[derive(PartialEq, Clone, Copy)]
@@ -37,171 +60,442 @@ pub enum OutputMode {
     GithubAction,
 }
 
-/// assign ids to advisories in a particular repo_path
-pub fn update_advisories(repo_path: &Path, output_mode: OutputMode) {
-    let db = rustsec::Database::open(repo_path).unwrap_or_else(|e| {
+/// Open every advisory database rooted at the given paths.
+///
+/// Each path is first tried directly as a database (a single git repo
+/// checkout). If that fails, it is treated as a root directory containing
+/// several database checkouts as immediate subdirectories (mirroring
+/// cargo-deny's advisories layout), and each subdirectory that opens
+/// successfully as a database is included.
+fn open_databases(paths: &[PathBuf]) -> Vec<(PathBuf, rustsec::Database)> {
+    let mut databases = vec![];
+
+    for path in paths {
+        if let Ok(db) = rustsec::Database::open(path) {
+            databases.push((path.clone(), db));
+            continue;
+        }
+
+        let Ok(entries) = fs::read_dir(path) else {
+            continue;
+        };
+
+        for entry in entries.flatten() {
+            let sub_path = entry.path();
+            if sub_path.is_dir() {
+                if let Ok(db) = rustsec::Database::open(&sub_path) {
+                    databases.push((sub_path, db));
+                }
+            }
+        }
+    }
+
+    databases
+}
+
+/// check advisories in the database(s) rooted at `repo_paths` against NVD and GHSA data
+pub fn update_advisories(
+    repo_paths: &[PathBuf],
+    output_mode: OutputMode,
+    nvd_api_key: Option<&str>,
+    github_token: Option<&str>,
+) {
+    let databases = open_databases(repo_paths);
+
+    if databases.is_empty() {
         status_err!(
-            "couldn't open advisory DB repo from {}: {}",
-            repo_path.display(),
-            e
+            "couldn't open any advisory DB repo from {}",
+            repo_paths
+                .iter()
+                .map(|p| p.display().to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
         );
         exit(1);
-    });
+    }
 
-    let advisories = db.iter();
+    let total_advisories: usize = databases.iter().map(|(_, db)| db.iter().len()).sum();
 
     // Ensure we're parsing some advisories
-    if advisories.len() == 0 {
+    if total_advisories == 0 {
         status_err!("no advisories found!");
         exit(1);
     }
 
     if output_mode == OutputMode::HumanReadable {
-        status_ok!(
-            "Loaded",
-            "{} security advisories (from {})",
-            advisories.len(),
-            repo_path.display()
-        );
+        for (path, db) in &databases {
+            status_ok!(
+                "Loaded",
+                "{} security advisories (from {})",
+                db.iter().len(),
+                path.display()
+            );
+        }
     }
 
-    for advisory in advisories {
-        let advisory_clone = advisory.clone();
-        let metadata = advisory_clone.metadata;
-        let advisory_id = metadata.id;
-        //println!("{}", advisory_id);
-
-        // Look for an existing CVE id
-        let cve_ids = metadata
-            .aliases
-            .iter()
-            .chain(std::iter::once(&advisory_id))
-            .filter(|alias| alias.kind() == rustsec::advisory::id::Kind::CVE);
-
-        let mut nvd_scores: HashSet<cvss::v3::Base> = HashSet::new();
-        let mut references: Vec<Url> = vec![];
-        let mut broken_cve_aliases: Vec<rustsec::advisory::id::Id> = vec![];
-        for id in cve_ids {
-            let info = fetch_cve(id);
-
-            match info {
-                Ok(Some(CveInfo {
-                    cvss: Some(ref nvd_cvss),
-                    references: _,
-                })) => {
-                    let _ = nvd_scores.insert(nvd_cvss.clone());
-                }
-                Ok(_) => (),
-                Err(_) => broken_cve_aliases.push(id.clone()),
-            }
+    let nvd_cves = fetch_all_cves(nvd_api_key).unwrap_or_else(|()| {
+        status_err!("couldn't fetch CVE data from the NVD 2.0 API");
+        exit(1);
+    });
+
+    // Tracks, for each CVE/GHSA alias already seen, which database first
+    // reported it and with what CVSS score — so an advisory that appears in
+    // more than one database is reconciled against NVD/GHSA only once, while
+    // a CVSS disagreement between the databases themselves is still caught.
+    let mut seen_aliases: HashMap<String, (PathBuf, Option<cvss::v3::Base>)> = HashMap::new();
+
+    for (db_path, db) in &databases {
+        for advisory in db.iter() {
+            let advisory_clone = advisory.clone();
+            let metadata = advisory_clone.metadata;
+            let advisory_id = metadata.id;
+            //println!("{}", advisory_id);
+
+            let all_aliases: Vec<_> = metadata
+                .aliases
+                .iter()
+                .chain(std::iter::once(&advisory_id))
+                .collect();
 
-            match info {
-                Ok(Some(CveInfo {
-                    cvss: _,
-                    references: nvd_references,
-                })) => {
-                    references.append(&mut nvd_references.clone());
+            let mut duplicate_of = None;
+            for alias in &all_aliases {
+                match seen_aliases.get(alias.as_str()) {
+                    Some((other_path, other_cvss)) if other_path != db_path => {
+                        if *other_cvss != advisory.metadata.cvss {
+                            println!(
+                            "Cross-DB inconsistency: cvss for {} differs between {} ({}) and {} ({})",
+                            alias,
+                            other_path.display(),
+                            other_cvss
+                                .as_ref()
+                                .map(ToString::to_string)
+                                .unwrap_or_else(|| "none".to_owned()),
+                            db_path.display(),
+                            advisory
+                                .metadata
+                                .cvss
+                                .as_ref()
+                                .map(ToString::to_string)
+                                .unwrap_or_else(|| "none".to_owned()),
+                        );
+                        }
+                        duplicate_of = Some(other_path.clone());
+                    }
+                    _ => {
+                        seen_aliases.insert(
+                            alias.to_string(),
+                            (db_path.clone(), advisory.metadata.cvss.clone()),
+                        );
+                    }
                 }
-                _ => (),
             }
-        }
-
-        for broken_alias in broken_cve_aliases {
-            println!("Broken alias for {}: {}", advisory_id, broken_alias);
-        }
 
-        // Try to extract ghsa ids from references
-        // to add it is missing
-        let mut ghsa_ids: Vec<rustsec::advisory::id::Id> = vec![];
-        for reference in references {
-            let s_ref = reference.as_str();
-
-            if s_ref.contains("rustsec")
-                || s_ref.contains("https://crates.io")
-                || s_ref.contains("RustSec")
-                || s_ref.contains("RUSTSEC-")
-            {
+            // Already reconciled against NVD/GHSA as part of another database.
+            if duplicate_of.is_some() {
                 continue;
             }
 
-            if s_ref.contains("GHSA-") {
-                let begin = s_ref.find("GHSA-").unwrap();
-                let ghsa = &s_ref[begin..begin + 19];
-                ghsa_ids.push(ghsa.parse().unwrap());
-                continue;
+            // Look for an existing CVE id
+            let cve_ids = all_aliases
+                .iter()
+                .filter(|alias| alias.kind() == rustsec::advisory::id::Kind::CVE);
+
+            // CVSS scores reported by external sources (NVD, GHSA) for this
+            // advisory's CVE/GHSA aliases; more than one distinct value across
+            // sources is reported as an inconsistency below.
+            let mut external_scores: HashSet<cvss::v3::Base> = HashSet::new();
+            let mut references: Vec<Url> = vec![];
+            // Most recent `lastModified` date (the `YYYY-MM-DD` prefix of NVD's
+            // timestamp) seen across this advisory's CVE aliases.
+            let mut latest_modified: Option<String> = None;
+            for id in cve_ids {
+                let Some(info) = nvd_cves.get(id.as_str()) else {
+                    continue;
+                };
+
+                if let Some(ref nvd_cvss) = info.cvss {
+                    let _ = external_scores.insert(nvd_cvss.clone());
+                }
+
+                references.append(&mut info.references.clone());
+
+                if let Some(modified) = info.modified.as_deref().map(|m| m[..10].to_owned()) {
+                    let is_newer = match &latest_modified {
+                        Some(prev) => modified > *prev,
+                        None => true,
+                    };
+                    if is_newer {
+                        latest_modified = Some(modified);
+                    }
+                }
             }
 
-            let mut complete_references = advisory.metadata.references.clone();
-            if let Some(u) = advisory.metadata.url.as_ref() {
-                complete_references.push(u.clone());
+            // Flag advisories whose content predates NVD's most recent revision
+            // of the underlying CVE.
+            if let Some(modified) = &latest_modified {
+                let published = advisory.metadata.date.to_string();
+                if *modified > published {
+                    println!("Stale since {}: {}", modified, advisory_id);
+                }
             }
-            if complete_references
-                .iter()
-                .find(|u| **u == reference)
-                .is_none()
-            {
-                println!("Missing reference for {}: {}", advisory_id, reference);
+
+            // Try to extract ghsa ids from references
+            // to add it is missing
+            let mut ghsa_ids: Vec<rustsec::advisory::id::Id> = vec![];
+            for reference in references {
+                let s_ref = reference.as_str();
+
+                if s_ref.contains("rustsec")
+                    || s_ref.contains("https://crates.io")
+                    || s_ref.contains("RustSec")
+                    || s_ref.contains("RUSTSEC-")
+                {
+                    continue;
+                }
+
+                if s_ref.contains("GHSA-") {
+                    let begin = s_ref.find("GHSA-").unwrap();
+                    let ghsa = &s_ref[begin..begin + 19];
+                    ghsa_ids.push(ghsa.parse().unwrap());
+                    continue;
+                }
+
+                let mut complete_references = advisory.metadata.references.clone();
+                if let Some(u) = advisory.metadata.url.as_ref() {
+                    complete_references.push(u.clone());
+                }
+                if complete_references
+                    .iter()
+                    .find(|u| **u == reference)
+                    .is_none()
+                {
+                    println!("Missing reference for {}: {}", advisory_id, reference);
+                }
             }
-        }
 
-        for ghsa_id in ghsa_ids {
-            if !advisory.metadata.aliases.contains(&ghsa_id) {
-                // FIXME check if they are really Rust advisories
-                println!("New {} alias for {}", ghsa_id, advisory_id);
+            for ghsa_id in ghsa_ids {
+                let ghsa_advisory = github_token.and_then(|token| {
+                    crate::ghsa::fetch_ghsa_advisory(ghsa_id.as_str(), token)
+                        .ok()
+                        .flatten()
+                });
+
+                if let Some(ref ghsa_cvss) = ghsa_advisory.as_ref().and_then(|a| a.cvss.clone()) {
+                    let _ = external_scores.insert(ghsa_cvss.clone());
+                }
+
+                if !advisory.metadata.aliases.contains(&ghsa_id) {
+                    // Only propose the alias once GHSA itself confirms it covers
+                    // a Rust/crates.io package; without a token to check that,
+                    // don't guess.
+                    let is_rust_advisory = ghsa_advisory
+                        .as_ref()
+                        .map(GhsaAdvisory::is_rust_advisory)
+                        .unwrap_or(false);
+
+                    if is_rust_advisory {
+                        println!("New {} alias for {}", ghsa_id, advisory_id);
+                    }
+                } else if ghsa_advisory.as_ref().map(|a| a.withdrawn).unwrap_or(false) {
+                    // The RustSec advisory already references this GHSA id, but
+                    // GHSA has since withdrawn its record while ours is still active.
+                    println!("Withdrawn upstream: {} ({})", advisory_id, ghsa_id);
+                }
             }
-        }
 
-        if nvd_scores.len() == 1 {
-            let nvd_score = nvd_scores.iter().next().unwrap();
-            if let Some(ref current_cvss) = advisory.metadata.cvss {
-                if current_cvss != nvd_score {
-                    println!("Potential cvss update for {}: {}", advisory_id, nvd_score)
+            if external_scores.len() == 1 {
+                let external_score = external_scores.iter().next().unwrap();
+                if let Some(ref current_cvss) = advisory.metadata.cvss {
+                    if current_cvss != external_score {
+                        println!(
+                            "Potential cvss update for {}: {}",
+                            advisory_id, external_score
+                        )
+                    }
+                } else {
+                    println!("Add cvss for {}: {}", advisory_id, external_score);
                 }
-            } else {
-                println!("Add cvss for {}: {}", advisory_id, nvd_score);
+            } else if external_scores.len() > 1 {
+                println!(
+                    "Inconsistency: {} cvss values for {}",
+                    external_scores.len(),
+                    advisory_id
+                );
             }
-        } else if nvd_scores.len() > 1 {
-            println!(
-                "Inconsistency: {} cvss values for {}",
-                nvd_scores.len(),
-                advisory_id
-            );
         }
     }
 }
 
 // Interesting parts of NVD data
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct CveInfo {
     cvss: Option<cvss::v3::Base>,
     references: Vec<Url>,
+    /// NVD's `lastModified` timestamp, as an ISO-8601 string; compared against
+    /// the advisory's own `date` to flag advisories NVD has since revised.
+    modified: Option<String>,
 }
 
-fn fetch_cve(id: &rustsec::advisory::id::Id) -> Result<Option<CveInfo>, ()> {
-    let response = ureq::get(&format!("{}/{}", NVD_API_URL, id))
-        .call()
-        .map_err(|_| ())?;
-    if response.status() == 404 {
-        return Ok(None);
+/// Read the NVD API key, preferring an explicit CLI flag over the
+/// `NVD_API_KEY` environment variable.
+pub fn nvd_api_key(flag: Option<&str>) -> Option<String> {
+    flag.map(ToOwned::to_owned)
+        .or_else(|| env::var(NVD_API_KEY_ENV_VAR).ok())
+}
+
+/// Fetch every CVE known to NVD, paging through the `/cves/2.0` endpoint,
+/// and index it by CVE id.
+///
+/// A single paginated crawl is far cheaper than issuing one `cveId`-filtered
+/// request per advisory, but it changes the failure granularity: the old
+/// per-CVE lookup reported "Broken alias for {id}: {alias}" when a single
+/// fetch or parse failed, attributing it to one advisory. Here, a single
+/// page failing (after `get_with_backoff`'s own rate-limit retries are
+/// exhausted, or a malformed response body) is logged and that page is
+/// skipped rather than aborting the whole crawl, mirroring the old
+/// isolated-failure model at page granularity instead of per-CVE. Only
+/// more than [`NVD_MAX_CONSECUTIVE_PAGE_FAILURES`] consecutive page failures
+/// in a row give up on the crawl entirely via `Err(())`. A CVE id that NVD simply
+/// doesn't know about still just produces no entry in the returned map, as
+/// before.
+fn fetch_all_cves(api_key: Option<&str>) -> Result<HashMap<String, CveInfo>, ()> {
+    let mut cves = HashMap::new();
+    let mut start_index = 0u32;
+    let mut consecutive_page_failures = 0u32;
+
+    loop {
+        match fetch_cve_page(start_index, api_key) {
+            Ok(page) => {
+                consecutive_page_failures = 0;
+
+                if page.vulnerabilities.is_empty() {
+                    break;
+                }
+
+                for vulnerability in &page.vulnerabilities {
+                    let cve = &vulnerability["cve"];
+                    let Some(id) = cve["id"].as_str() else {
+                        continue;
+                    };
+
+                    let cvss = cve["metrics"]["cvssMetricV31"][0]["cvssData"]["vectorString"]
+                        .as_str()
+                        .or_else(|| {
+                            cve["metrics"]["cvssMetricV30"][0]["cvssData"]["vectorString"].as_str()
+                        })
+                        .and_then(|s| s.parse().ok());
+
+                    let references = cve["references"]
+                        .as_array()
+                        .into_iter()
+                        .flatten()
+                        .filter_map(|reference| reference["url"].as_str())
+                        .filter_map(|url| Url::parse(url).ok())
+                        .collect();
+
+                    let modified = cve["lastModified"].as_str().map(ToOwned::to_owned);
+
+                    cves.insert(
+                        id.to_owned(),
+                        CveInfo {
+                            cvss,
+                            references,
+                            modified,
+                        },
+                    );
+                }
+
+                start_index += page.vulnerabilities.len() as u32;
+
+                if u64::from(start_index) >= page.total_results {
+                    break;
+                }
+            }
+            Err(()) => {
+                consecutive_page_failures += 1;
+                if consecutive_page_failures > NVD_MAX_CONSECUTIVE_PAGE_FAILURES {
+                    status_err!(
+                        "giving up on NVD CVE crawl after {} consecutive page failures (at index {})",
+                        consecutive_page_failures,
+                        start_index
+                    );
+                    return Err(());
+                }
+
+                status_err!(
+                    "couldn't fetch/parse NVD CVE page at index {}, skipping it",
+                    start_index
+                );
+                start_index += NVD_RESULTS_PER_PAGE;
+            }
+        }
+
+        sleep(Duration::from_millis(if api_key.is_some() {
+            NVD_API_SLEEP_MS_WITH_KEY
+        } else {
+            NVD_API_SLEEP_MS
+        }));
     }
+
+    Ok(cves)
+}
+
+/// One page of NVD's `/cves/2.0` results.
+struct CvePage {
+    vulnerabilities: Vec<serde_json::Value>,
+    total_results: u64,
+}
+
+/// Fetch and parse a single page of NVD's `/cves/2.0` results at `start_index`.
+fn fetch_cve_page(start_index: u32, api_key: Option<&str>) -> Result<CvePage, ()> {
+    let response = get_with_backoff(|| {
+        let mut request = ureq::get(NVD_API_URL)
+            .query("resultsPerPage", &NVD_RESULTS_PER_PAGE.to_string())
+            .query("startIndex", &start_index.to_string());
+
+        if let Some(key) = api_key {
+            request = request.set("apiKey", key);
+        }
+
+        request
+    })?;
+
     let body = response.into_string().map_err(|_| ())?;
+    let data: serde_json::Value = serde_json::from_str(&body).map_err(|_| ())?;
 
-    let data: serde_json::Value = serde_json::from_str(&body).unwrap();
-    let cvss = data["result"]["CVE_Items"][0]["impact"]["baseMetricV3"]["cvssV3"]["vectorString"]
-        .as_str()
-        .and_then(|s| s.parse().ok());
-
-    let mut references = vec![];
-    let r_references = data["result"]["CVE_Items"][0]["cve"]["references"]["reference_data"]
-        .as_array()
-        .unwrap();
-    for r_ref in r_references {
-        let url = Url::parse(r_ref["url"].as_str().unwrap()).unwrap();
-        references.push(url);
+    let vulnerabilities = data["vulnerabilities"].as_array().ok_or(())?.clone();
+    let total_results = data["totalResults"].as_u64().unwrap_or(0);
+
+    Ok(CvePage {
+        vulnerabilities,
+        total_results,
+    })
+}
+
+/// Issue a request built by `build_request`, retrying with adaptive backoff
+/// when NVD responds with a rate-limiting status (403/429), honoring the
+/// `Retry-After` header when present and otherwise falling back to
+/// exponential backoff.
+fn get_with_backoff<F>(build_request: F) -> Result<ureq::Response, ()>
+where
+    F: Fn() -> ureq::Request,
+{
+    for attempt in 0..=NVD_MAX_RETRIES {
+        match build_request().call() {
+            Ok(response) => return Ok(response),
+            Err(ureq::Error::Status(403 | 429, response)) => {
+                let retry_after = response
+                    .header("Retry-After")
+                    .and_then(|value| value.parse::<u64>().ok())
+                    .unwrap_or_else(|| 1 << attempt.min(6));
+
+                sleep(Duration::from_secs(retry_after));
+            }
+            Err(_) => return Err(()),
+        }
     }
 
-    sleep(Duration::from_millis(NVD_API_SLEEP_MS));
-    Ok(Some(CveInfo { cvss, references }))
+    Err(())
 }
 
 /*
@@ -69,6 +69,60 @@ pub enum MetricType {
 
     /// Confidentiality Requirement (CR)
     CR,
+
+    /// Modified Attack Vector (MAV)
+    MAV,
+
+    /// Modified Attack Complexity (MAC)
+    MAC,
+
+    /// Modified Privileges Required (MPR)
+    MPR,
+
+    /// Modified User Interaction (MUI)
+    MUI,
+
+    /// Modified Scope (MS)
+    MS,
+
+    /// Modified Confidentiality Impact (MC)
+    MC,
+
+    /// Modified Integrity Impact (MI)
+    MI,
+
+    /// Modified Availability Impact (MA)
+    MA,
+
+    /// Attack Requirements (AT)
+    AT,
+
+    /// Vulnerable System Confidentiality Impact (VC)
+    VC,
+
+    /// Vulnerable System Integrity Impact (VI)
+    VI,
+
+    /// Vulnerable System Availability Impact (VA)
+    VA,
+
+    /// Subsequent System Confidentiality Impact (SC)
+    SC,
+
+    /// Subsequent System Integrity Impact (SI)
+    SI,
+
+    /// Subsequent System Availability Impact (SA)
+    SA,
+
+    /// Authentication (Au) - CVSS v2.0
+    AU,
+
+    /// Collateral Damage Potential (CDP) - CVSS v2.0
+    CDP,
+
+    /// Target Distribution (TD) - CVSS v2.0
+    TD,
 }
 
 impl MetricType {
@@ -89,6 +143,24 @@ impl MetricType {
             Self::AR => "AR",
             Self::IR => "IR",
             Self::CR => "CR",
+            Self::MAV => "MAV",
+            Self::MAC => "MAC",
+            Self::MPR => "MPR",
+            Self::MUI => "MUI",
+            Self::MS => "MS",
+            Self::MC => "MC",
+            Self::MI => "MI",
+            Self::MA => "MA",
+            Self::AT => "AT",
+            Self::VC => "VC",
+            Self::VI => "VI",
+            Self::VA => "VA",
+            Self::SC => "SC",
+            Self::SI => "SI",
+            Self::SA => "SA",
+            Self::AU => "Au",
+            Self::CDP => "CDP",
+            Self::TD => "TD",
         }
     }
 
@@ -109,6 +181,24 @@ impl MetricType {
             Self::AR => "Availability Requirement",
             Self::IR => "Integrity Requirement",
             Self::CR => "Confidentiality Requirement",
+            Self::MAV => "Modified Attack Vector",
+            Self::MAC => "Modified Attack Complexity",
+            Self::MPR => "Modified Privileges Required",
+            Self::MUI => "Modified User Interaction",
+            Self::MS => "Modified Scope",
+            Self::MC => "Modified Confidentiality Impact",
+            Self::MI => "Modified Integrity Impact",
+            Self::MA => "Modified Availability Impact",
+            Self::AT => "Attack Requirements",
+            Self::VC => "Vulnerable System Confidentiality Impact",
+            Self::VI => "Vulnerable System Integrity Impact",
+            Self::VA => "Vulnerable System Availability Impact",
+            Self::SC => "Subsequent System Confidentiality Impact",
+            Self::SI => "Subsequent System Integrity Impact",
+            Self::SA => "Subsequent System Availability Impact",
+            Self::AU => "Authentication",
+            Self::CDP => "Collateral Damage Potential",
+            Self::TD => "Target Distribution",
         }
     }
 }
@@ -134,10 +224,28 @@ impl FromStr for MetricType {
             "UI" => Ok(Self::UI),
             "E" => Ok(Self::E),
             "RL" => Ok(Self::RL),
-            "RC" => Ok(Self::RL),
+            "RC" => Ok(Self::RC),
             "AR" => Ok(Self::AR),
             "IR" => Ok(Self::IR),
             "CR" => Ok(Self::CR),
+            "MAV" => Ok(Self::MAV),
+            "MAC" => Ok(Self::MAC),
+            "MPR" => Ok(Self::MPR),
+            "MUI" => Ok(Self::MUI),
+            "MS" => Ok(Self::MS),
+            "MC" => Ok(Self::MC),
+            "MI" => Ok(Self::MI),
+            "MA" => Ok(Self::MA),
+            "AT" => Ok(Self::AT),
+            "VC" => Ok(Self::VC),
+            "VI" => Ok(Self::VI),
+            "VA" => Ok(Self::VA),
+            "SC" => Ok(Self::SC),
+            "SI" => Ok(Self::SI),
+            "SA" => Ok(Self::SA),
+            "AU" => Ok(Self::AU),
+            "CDP" => Ok(Self::CDP),
+            "TD" => Ok(Self::TD),
             _ => Err(Error::UnknownMetric { name: s.to_owned() }),
         }
     }
@@ -0,0 +1,13 @@
+//! Common Vulnerability Scoring System (v2.0)
+//!
+//! <https://www.first.org/cvss/v2/guide>
+
+pub mod base;
+pub mod environmental;
+pub mod temporal;
+
+mod score;
+
+pub use self::{
+    base::Base, environmental::Environmental, score::Score, temporal::Temporal,
+};
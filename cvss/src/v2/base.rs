@@ -0,0 +1,261 @@
+//! CVSS v2.0 Base Metric Group
+
+pub mod a;
+pub mod ac;
+pub mod au;
+pub mod av;
+pub mod c;
+pub mod i;
+
+pub use self::{
+    a::AvailabilityImpact, ac::AccessComplexity, au::Authentication, av::AccessVector,
+    c::ConfidentialityImpact, i::IntegrityImpact,
+};
+
+use super::Score;
+use crate::{Error, Metric, MetricType, Result};
+use alloc::{
+    borrow::ToOwned,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{fmt, str::FromStr};
+
+#[cfg(feature = "serde")]
+use serde::{de, ser, Deserialize, Serialize};
+
+/// CVSS v2.0 Base Metric Group
+///
+/// Described in CVSS v2.0 Specification: Section 2.1:
+/// <https://www.first.org/cvss/v2/guide#2-1-Base-Metrics>
+///
+/// > The base metric group captures the characteristics of a vulnerability that are constant
+/// > with time and across user environments.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Base {
+    /// Access Vector (AV)
+    pub av: AccessVector,
+
+    /// Access Complexity (AC)
+    pub ac: AccessComplexity,
+
+    /// Authentication (Au)
+    pub au: Authentication,
+
+    /// Confidentiality Impact (C)
+    pub c: ConfidentialityImpact,
+
+    /// Integrity Impact (I)
+    pub i: IntegrityImpact,
+
+    /// Availability Impact (A)
+    pub a: AvailabilityImpact,
+}
+
+impl Base {
+    /// Calculate the CVSS v2.0 Base score.
+    ///
+    /// Described in CVSS v2.0 Specification: Section 3.2.1:
+    /// <https://www.first.org/cvss/v2/guide#3-2-1-Base-Equation>
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn score(&self) -> Score {
+        let impact = self.impact_subscore();
+        let exploitability = self.exploitability().value();
+
+        let f_impact = if impact == 0.0 { 0.0 } else { 1.176 };
+        let score = ((0.6 * impact) + (0.4 * exploitability) - 1.5) * f_impact;
+
+        Score::new(score).round()
+    }
+
+    /// Calculate the Exploitability sub-score.
+    pub fn exploitability(&self) -> Score {
+        (20.0 * self.av.score() * self.ac.score() * self.au.score()).into()
+    }
+
+    /// Calculate the Impact sub-score.
+    pub fn impact(&self) -> Score {
+        self.impact_subscore().into()
+    }
+
+    /// Calculate the CVSS v2.0 Severity according to the Qualitative
+    /// Severity Rating Scale (i.e. Low / Medium / High)
+    ///
+    /// Described in CVSS v2.0 Specification: Section 3.4:
+    /// <https://www.first.org/cvss/v2/guide#3-4-Qualitative-Severity-Rating-Scale>
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn severity(&self) -> &'static str {
+        self.score().severity()
+    }
+
+    fn impact_subscore(&self) -> f64 {
+        10.41 * (1.0 - (1.0 - self.c.score()) * (1.0 - self.i.score()) * (1.0 - self.a.score()))
+    }
+
+    /// Decompose the Base score into its constituent parts, suitable for
+    /// storage in structured records (e.g. protobuf/JSON schemas) without
+    /// the consumer having to re-derive them from the parsed vector string.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn to_scores(&self) -> Scores {
+        Scores {
+            vector: self.to_string(),
+            base_score: self.score().value(),
+            exploitability_score: self.exploitability().value(),
+            impact_score: self.impact().value(),
+            severity: self.severity(),
+        }
+    }
+}
+
+/// Decomposed CVSS v2.0 Base scores.
+///
+/// Returned by [`Base::to_scores`].
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Scores {
+    /// Full CVSS v2.0 vector string
+    pub vector: String,
+
+    /// Overall Base score
+    pub base_score: f64,
+
+    /// Exploitability sub-score
+    pub exploitability_score: f64,
+
+    /// Impact sub-score
+    pub impact_score: f64,
+
+    /// Qualitative Severity Rating (i.e. Low / Medium / High)
+    pub severity: &'static str,
+}
+
+impl fmt::Display for Base {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}/{}/{}/{}/{}/{}",
+            self.av, self.ac, self.au, self.c, self.i, self.a
+        )
+    }
+}
+
+impl FromStr for Base {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let component_vec = s
+            .split('/')
+            .map(|component| {
+                let mut parts = component.split(':');
+
+                let id = parts.next().ok_or_else(|| Error::InvalidComponent {
+                    component: component.to_owned(),
+                })?;
+
+                let value = parts.next().ok_or_else(|| Error::InvalidComponent {
+                    component: component.to_owned(),
+                })?;
+
+                if parts.next().is_some() {
+                    return Err(Error::InvalidComponent {
+                        component: component.to_owned(),
+                    });
+                }
+
+                Ok((id, value))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut av = None;
+        let mut ac = None;
+        let mut au = None;
+        let mut c = None;
+        let mut i = None;
+        let mut a = None;
+
+        for (id, value) in component_vec {
+            let id = id.to_ascii_uppercase();
+            let value = value.to_ascii_uppercase();
+
+            match id.parse::<MetricType>()? {
+                MetricType::AV => av = Some(value.parse()?),
+                MetricType::AC => ac = Some(value.parse()?),
+                MetricType::AU => au = Some(value.parse()?),
+                MetricType::C => c = Some(value.parse()?),
+                MetricType::I => i = Some(value.parse()?),
+                MetricType::A => a = Some(value.parse()?),
+                other => {
+                    return Err(Error::UnknownMetric {
+                        name: other.to_string(),
+                    })
+                }
+            }
+        }
+
+        Ok(Self {
+            av: required(av, "AV")?,
+            ac: required(ac, "AC")?,
+            au: required(au, "Au")?,
+            c: required(c, "C")?,
+            i: required(i, "I")?,
+            a: required(a, "A")?,
+        })
+    }
+}
+
+/// CVSS v2.0 Base metrics have no "Not Defined" default: every one of them is mandatory, so a
+/// vector string missing one is invalid rather than implicitly filled in.
+fn required<T>(value: Option<T>, id: &str) -> Result<T> {
+    value.ok_or_else(|| Error::InvalidComponent {
+        component: id.to_owned(),
+    })
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Base {
+    fn deserialize<D: de::Deserializer<'de>>(
+        deserializer: D,
+    ) -> core::result::Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl Serialize for Base {
+    fn serialize<S: ser::Serializer>(
+        &self,
+        serializer: S,
+    ) -> core::result::Result<S::Ok, S::Error> {
+        self.to_string().serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_VECTOR: &str = "AV:N/AC:L/Au:N/C:C/I:C/A:C";
+
+    #[test]
+    fn parses_and_round_trips() {
+        let base: Base = EXAMPLE_VECTOR.parse().unwrap();
+        assert_eq!(base.to_string(), EXAMPLE_VECTOR);
+    }
+
+    // Every Base metric is mandatory in CVSS v2.0: a vector missing one must error rather
+    // than silently defaulting it.
+    #[test]
+    fn errors_on_missing_mandatory_metric() {
+        let result = "AV:N/AC:L".parse::<Base>();
+        assert!(result.is_err());
+    }
+}
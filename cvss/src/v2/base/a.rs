@@ -0,0 +1,78 @@
+//! Availability Impact (A)
+
+use crate::{Error, Metric, MetricType};
+use alloc::borrow::ToOwned;
+use core::{fmt, str::FromStr};
+
+/// Availability Impact (A) - CVSS v2.0 Base Metric Group
+///
+/// Described in CVSS v2.0 Specification: Section 2.1.6:
+/// <https://www.first.org/cvss/v2/guide#2-1-6-Availability-Impact-A>
+///
+/// > This metric measures the impact to availability of a successfully exploited vulnerability.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum AvailabilityImpact {
+    /// None (N)
+    ///
+    /// > There is no impact to the availability of the system.
+    None,
+
+    /// Partial (P)
+    ///
+    /// > There is reduced performance or interruptions in resource availability.
+    Partial,
+
+    /// Complete (C)
+    ///
+    /// > There is a total shutdown of the affected resource. The attacker can render the resource
+    /// > completely unavailable.
+    Complete,
+}
+
+impl Default for AvailabilityImpact {
+    fn default() -> AvailabilityImpact {
+        AvailabilityImpact::None
+    }
+}
+
+impl Metric for AvailabilityImpact {
+    const TYPE: MetricType = MetricType::A;
+
+    fn score(self) -> f64 {
+        match self {
+            AvailabilityImpact::None => 0.0,
+            AvailabilityImpact::Partial => 0.275,
+            AvailabilityImpact::Complete => 0.660,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            AvailabilityImpact::None => "N",
+            AvailabilityImpact::Partial => "P",
+            AvailabilityImpact::Complete => "C",
+        }
+    }
+}
+
+impl fmt::Display for AvailabilityImpact {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::name(), self.as_str())
+    }
+}
+
+impl FromStr for AvailabilityImpact {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "N" => Ok(AvailabilityImpact::None),
+            "P" => Ok(AvailabilityImpact::Partial),
+            "C" => Ok(AvailabilityImpact::Complete),
+            _ => Err(Error::InvalidMetric {
+                metric_type: Self::TYPE,
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
@@ -0,0 +1,81 @@
+//! Access Complexity (AC)
+
+use crate::{Error, Metric, MetricType};
+use alloc::borrow::ToOwned;
+use core::{fmt, str::FromStr};
+
+/// Access Complexity (AC) - CVSS v2.0 Base Metric Group
+///
+/// Described in CVSS v2.0 Specification: Section 2.1.2:
+/// <https://www.first.org/cvss/v2/guide#2-1-2-Access-Complexity-AC>
+///
+/// > This metric measures the complexity of the attack required to exploit the vulnerability
+/// > once an attacker has gained access to the target system.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum AccessComplexity {
+    /// High (H)
+    ///
+    /// > Specialized access conditions exist, e.g. a race condition with a narrow window, or a
+    /// > social engineering step is required.
+    High,
+
+    /// Medium (M)
+    ///
+    /// > The access conditions are somewhat specialized, e.g. the attacking party is limited to a
+    /// > group of systems or users.
+    Medium,
+
+    /// Low (L)
+    ///
+    /// > Specialized access conditions or extenuating circumstances do not exist, e.g. the system
+    /// > is generally available and exploitation does not require additional information gathering.
+    Low,
+}
+
+impl Default for AccessComplexity {
+    fn default() -> AccessComplexity {
+        AccessComplexity::Low
+    }
+}
+
+impl Metric for AccessComplexity {
+    const TYPE: MetricType = MetricType::AC;
+
+    fn score(self) -> f64 {
+        match self {
+            AccessComplexity::High => 0.35,
+            AccessComplexity::Medium => 0.61,
+            AccessComplexity::Low => 0.71,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            AccessComplexity::High => "H",
+            AccessComplexity::Medium => "M",
+            AccessComplexity::Low => "L",
+        }
+    }
+}
+
+impl fmt::Display for AccessComplexity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::name(), self.as_str())
+    }
+}
+
+impl FromStr for AccessComplexity {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "H" => Ok(AccessComplexity::High),
+            "M" => Ok(AccessComplexity::Medium),
+            "L" => Ok(AccessComplexity::Low),
+            _ => Err(Error::InvalidMetric {
+                metric_type: Self::TYPE,
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
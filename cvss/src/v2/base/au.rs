@@ -0,0 +1,80 @@
+//! Authentication (Au)
+
+use crate::{Error, Metric, MetricType};
+use alloc::borrow::ToOwned;
+use core::{fmt, str::FromStr};
+
+/// Authentication (Au) - CVSS v2.0 Base Metric Group
+///
+/// Described in CVSS v2.0 Specification: Section 2.1.3:
+/// <https://www.first.org/cvss/v2/guide#2-1-3-Authentication-Au>
+///
+/// > This metric measures the number of times an attacker must authenticate to a target in order
+/// > to exploit a vulnerability.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum Authentication {
+    /// Multiple (M)
+    ///
+    /// > Exploiting the vulnerability requires that the attacker authenticate two or more times,
+    /// > even if the same credentials are used each time.
+    Multiple,
+
+    /// Single (S)
+    ///
+    /// > The attacker is required to log into the system (e.g., at a console or via remote login)
+    /// > before exploiting the vulnerability.
+    Single,
+
+    /// None (N)
+    ///
+    /// > Authentication is not required to exploit the vulnerability.
+    None,
+}
+
+impl Default for Authentication {
+    fn default() -> Authentication {
+        Authentication::None
+    }
+}
+
+impl Metric for Authentication {
+    const TYPE: MetricType = MetricType::AU;
+
+    fn score(self) -> f64 {
+        match self {
+            Authentication::Multiple => 0.45,
+            Authentication::Single => 0.56,
+            Authentication::None => 0.704,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Authentication::Multiple => "M",
+            Authentication::Single => "S",
+            Authentication::None => "N",
+        }
+    }
+}
+
+impl fmt::Display for Authentication {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::name(), self.as_str())
+    }
+}
+
+impl FromStr for Authentication {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "M" => Ok(Authentication::Multiple),
+            "S" => Ok(Authentication::Single),
+            "N" => Ok(Authentication::None),
+            _ => Err(Error::InvalidMetric {
+                metric_type: Self::TYPE,
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
@@ -0,0 +1,79 @@
+//! Integrity Impact (I)
+
+use crate::{Error, Metric, MetricType};
+use alloc::borrow::ToOwned;
+use core::{fmt, str::FromStr};
+
+/// Integrity Impact (I) - CVSS v2.0 Base Metric Group
+///
+/// Described in CVSS v2.0 Specification: Section 2.1.5:
+/// <https://www.first.org/cvss/v2/guide#2-1-5-Integrity-Impact-I>
+///
+/// > This metric measures the impact to integrity of a successfully exploited vulnerability.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum IntegrityImpact {
+    /// None (N)
+    ///
+    /// > There is no impact to the integrity of the system.
+    None,
+
+    /// Partial (P)
+    ///
+    /// > Modification of some system files or information is possible, but the attacker does not
+    /// > have control over what can be modified, or the scope of what the attacker can affect is limited.
+    Partial,
+
+    /// Complete (C)
+    ///
+    /// > There is a total compromise of system integrity. The attacker can modify any files on
+    /// > the target system.
+    Complete,
+}
+
+impl Default for IntegrityImpact {
+    fn default() -> IntegrityImpact {
+        IntegrityImpact::None
+    }
+}
+
+impl Metric for IntegrityImpact {
+    const TYPE: MetricType = MetricType::I;
+
+    fn score(self) -> f64 {
+        match self {
+            IntegrityImpact::None => 0.0,
+            IntegrityImpact::Partial => 0.275,
+            IntegrityImpact::Complete => 0.660,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            IntegrityImpact::None => "N",
+            IntegrityImpact::Partial => "P",
+            IntegrityImpact::Complete => "C",
+        }
+    }
+}
+
+impl fmt::Display for IntegrityImpact {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::name(), self.as_str())
+    }
+}
+
+impl FromStr for IntegrityImpact {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "N" => Ok(IntegrityImpact::None),
+            "P" => Ok(IntegrityImpact::Partial),
+            "C" => Ok(IntegrityImpact::Complete),
+            _ => Err(Error::InvalidMetric {
+                metric_type: Self::TYPE,
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
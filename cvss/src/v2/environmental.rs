@@ -0,0 +1,212 @@
+//! CVSS v2.0 Environmental Metric Group
+
+pub mod ar;
+pub mod cdp;
+pub mod cr;
+pub mod ir;
+pub mod td;
+
+pub use self::{
+    ar::AvailabilityRequirement, cdp::CollateralDamagePotential, cr::ConfidentialityRequirement,
+    ir::IntegrityRequirement, td::TargetDistribution,
+};
+
+use super::{Base, Score, Temporal};
+use crate::{Error, Metric, MetricType, Result};
+use alloc::{borrow::ToOwned, string::ToString, vec::Vec};
+use core::{fmt, str::FromStr};
+
+#[cfg(feature = "serde")]
+use {
+    alloc::string::String,
+    serde::{de, ser, Deserialize, Serialize},
+};
+
+/// CVSS v2.0 Environmental Metric Group
+///
+/// Described in CVSS v2.0 Specification: Section 2.3:
+/// <https://www.first.org/cvss/v2/guide#2-3-Environmental-Metrics>
+///
+/// > These metrics enable the analyst to customize the CVSS score depending on the importance
+/// > of the affected IT asset to a user's organization, measured in terms of confidentiality,
+/// > integrity, and availability.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Environmental {
+    /// Collateral Damage Potential (CDP)
+    pub cdp: Option<CollateralDamagePotential>,
+
+    /// Target Distribution (TD)
+    pub td: Option<TargetDistribution>,
+
+    /// Confidentiality Requirement (CR)
+    pub cr: Option<ConfidentialityRequirement>,
+
+    /// Integrity Requirement (IR)
+    pub ir: Option<IntegrityRequirement>,
+
+    /// Availability Requirement (AR)
+    pub ar: Option<AvailabilityRequirement>,
+}
+
+impl Environmental {
+    /// Calculate the Environmental CVSS score, given the Base and Temporal
+    /// metrics it modifies.
+    ///
+    /// Described in CVSS v2.0 Specification: Section 3.3.2:
+    /// <https://www.first.org/cvss/v2/guide#3-3-2-Environmental-Equation>
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn score(&self, base: &Base, temporal: &Temporal) -> Score {
+        let cdp = self.cdp.unwrap_or_default().score();
+        let td = self.td.unwrap_or_default().score();
+
+        let adjusted_temporal = self.adjusted_temporal(base, temporal);
+        let score = (adjusted_temporal + (10.0 - adjusted_temporal) * cdp) * td;
+
+        Score::new(score).round()
+    }
+
+    /// Calculate the Adjusted Temporal score, i.e. the Temporal score
+    /// recomputed using the Adjusted Base score.
+    ///
+    /// Per CVSS v2.0 Specification: Section 3.3.2, AdjustedTemporal is itself
+    /// round-half-up rounded to one decimal place, same as AdjustedBase.
+    fn adjusted_temporal(&self, base: &Base, temporal: &Temporal) -> f64 {
+        let e = temporal.e.unwrap_or_default().score();
+        let rl = temporal.rl.unwrap_or_default().score();
+        let rc = temporal.rc.unwrap_or_default().score();
+
+        Score::new(self.adjusted_base(base) * e * rl * rc)
+            .round()
+            .value()
+    }
+
+    /// Calculate the Adjusted Base score, i.e. the Base score recomputed
+    /// using the Adjusted Impact.
+    ///
+    /// Per CVSS v2.0 Specification: Section 3.3.2, AdjustedBase is rounded to
+    /// one decimal place before being fed into AdjustedTemporal, the same as
+    /// [`super::Temporal::score`] reuses the already-rounded `Base::score()`.
+    fn adjusted_base(&self, base: &Base) -> f64 {
+        let exploitability = base.exploitability().value();
+        let adjusted_impact = self.adjusted_impact(base);
+
+        let f_impact = if adjusted_impact == 0.0 { 0.0 } else { 1.176 };
+        let adjusted_base = ((0.6 * adjusted_impact) + (0.4 * exploitability) - 1.5) * f_impact;
+
+        Score::new(adjusted_base).round().value()
+    }
+
+    /// Calculate the Adjusted Impact sub-score, capped at 10.0.
+    fn adjusted_impact(&self, base: &Base) -> f64 {
+        let cr = self.cr.unwrap_or_default().score();
+        let ir = self.ir.unwrap_or_default().score();
+        let ar = self.ar.unwrap_or_default().score();
+
+        (10.41
+            * (1.0
+                - (1.0 - base.c.score() * cr)
+                    * (1.0 - base.i.score() * ir)
+                    * (1.0 - base.a.score() * ar)))
+            .min(10.0)
+    }
+
+    /// Calculate the Environmental Severity according to the Qualitative
+    /// Severity Rating Scale (i.e. Low / Medium / High)
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn severity(&self, base: &Base, temporal: &Temporal) -> &'static str {
+        self.score(base, temporal).severity()
+    }
+}
+
+impl fmt::Display for Environmental {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut metrics = [
+            self.cdp.map(|m| m.to_string()),
+            self.td.map(|m| m.to_string()),
+            self.cr.map(|m| m.to_string()),
+            self.ir.map(|m| m.to_string()),
+            self.ar.map(|m| m.to_string()),
+        ]
+        .into_iter()
+        .flatten();
+
+        if let Some(metric) = metrics.next() {
+            write!(f, "{metric}")?;
+        }
+
+        for metric in metrics {
+            write!(f, "/{metric}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Environmental {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut metrics = Self::default();
+
+        for component in s.split('/') {
+            let mut parts = component.split(':');
+
+            let id = parts.next().ok_or_else(|| Error::InvalidComponent {
+                component: component.to_owned(),
+            })?;
+
+            let value = parts.next().ok_or_else(|| Error::InvalidComponent {
+                component: component.to_owned(),
+            })?;
+
+            if parts.next().is_some() {
+                return Err(Error::InvalidComponent {
+                    component: component.to_owned(),
+                });
+            }
+
+            let id = id.to_ascii_uppercase();
+            let value = value.to_ascii_uppercase();
+
+            match id.parse::<MetricType>()? {
+                MetricType::CDP => metrics.cdp = Some(value.parse()?),
+                MetricType::TD => metrics.td = Some(value.parse()?),
+                MetricType::CR => metrics.cr = Some(value.parse()?),
+                MetricType::IR => metrics.ir = Some(value.parse()?),
+                MetricType::AR => metrics.ar = Some(value.parse()?),
+                other => {
+                    return Err(Error::UnknownMetric {
+                        name: other.to_string(),
+                    })
+                }
+            }
+        }
+
+        Ok(metrics)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Environmental {
+    fn deserialize<D: de::Deserializer<'de>>(
+        deserializer: D,
+    ) -> core::result::Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl Serialize for Environmental {
+    fn serialize<S: ser::Serializer>(
+        &self,
+        serializer: S,
+    ) -> core::result::Result<S::Ok, S::Error> {
+        self.to_string().serialize(serializer)
+    }
+}
@@ -0,0 +1,92 @@
+//! Availability Requirement (AR)
+
+use crate::{Error, Metric, MetricType, Result};
+use alloc::borrow::ToOwned;
+use core::{fmt, str::FromStr};
+
+/// Availability Requirement (AR) - CVSS v2.0 Environmental Metric Group
+///
+/// Described in CVSS v2.0 Specification: Section 2.3.1:
+/// <https://www.first.org/cvss/v2/guide#2-3-1-Collateral-Damage-Potential-CDP>
+///
+/// > This metric enables the analyst to customize the CVSS score depending on the importance of
+/// > the affected IT asset to a user's organization, measured in terms of availability.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum AvailabilityRequirement {
+    /// Not Defined (ND)
+    ///
+    /// > Assigning this value to the metric will not influence the score, and is a signal to
+    /// > the equation to skip this metric.
+    NotDefined,
+
+    /// Low (L)
+    ///
+    /// > Loss of availability is likely to have only a limited adverse effect on the
+    /// > organization or individuals associated with the organization (e.g., employees,
+    /// > customers).
+    Low,
+
+    /// Medium (M)
+    ///
+    /// > Loss of availability is likely to have a serious adverse effect on the organization or
+    /// > individuals associated with the organization (e.g., employees, customers).
+    Medium,
+
+    /// High (H)
+    ///
+    /// > Loss of availability is likely to have a catastrophic adverse effect on the
+    /// > organization or individuals associated with the organization (e.g., employees,
+    /// > customers).
+    High,
+}
+
+impl Default for AvailabilityRequirement {
+    fn default() -> AvailabilityRequirement {
+        AvailabilityRequirement::NotDefined
+    }
+}
+
+impl Metric for AvailabilityRequirement {
+    const TYPE: MetricType = MetricType::AR;
+
+    fn score(self) -> f64 {
+        match self {
+            AvailabilityRequirement::NotDefined => 1.0,
+            AvailabilityRequirement::Low => 0.5,
+            AvailabilityRequirement::Medium => 1.0,
+            AvailabilityRequirement::High => 1.51,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            AvailabilityRequirement::NotDefined => "ND",
+            AvailabilityRequirement::Low => "L",
+            AvailabilityRequirement::Medium => "M",
+            AvailabilityRequirement::High => "H",
+        }
+    }
+}
+
+impl fmt::Display for AvailabilityRequirement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::name(), self.as_str())
+    }
+}
+
+impl FromStr for AvailabilityRequirement {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "ND" => Ok(AvailabilityRequirement::NotDefined),
+            "L" => Ok(AvailabilityRequirement::Low),
+            "M" => Ok(AvailabilityRequirement::Medium),
+            "H" => Ok(AvailabilityRequirement::High),
+            _ => Err(Error::InvalidMetric {
+                metric_type: Self::TYPE,
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
@@ -0,0 +1,92 @@
+//! Confidentiality Requirement (CR)
+
+use crate::{Error, Metric, MetricType, Result};
+use alloc::borrow::ToOwned;
+use core::{fmt, str::FromStr};
+
+/// Confidentiality Requirement (CR) - CVSS v2.0 Environmental Metric Group
+///
+/// Described in CVSS v2.0 Specification: Section 2.3.1:
+/// <https://www.first.org/cvss/v2/guide#2-3-1-Collateral-Damage-Potential-CDP>
+///
+/// > This metric enables the analyst to customize the CVSS score depending on the importance of
+/// > the affected IT asset to a user's organization, measured in terms of confidentiality.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum ConfidentialityRequirement {
+    /// Not Defined (ND)
+    ///
+    /// > Assigning this value to the metric will not influence the score, and is a signal to
+    /// > the equation to skip this metric.
+    NotDefined,
+
+    /// Low (L)
+    ///
+    /// > Loss of confidentiality is likely to have only a limited adverse effect on the
+    /// > organization or individuals associated with the organization (e.g., employees,
+    /// > customers).
+    Low,
+
+    /// Medium (M)
+    ///
+    /// > Loss of confidentiality is likely to have a serious adverse effect on the organization
+    /// > or individuals associated with the organization (e.g., employees, customers).
+    Medium,
+
+    /// High (H)
+    ///
+    /// > Loss of confidentiality is likely to have a catastrophic adverse effect on the
+    /// > organization or individuals associated with the organization (e.g., employees,
+    /// > customers).
+    High,
+}
+
+impl Default for ConfidentialityRequirement {
+    fn default() -> ConfidentialityRequirement {
+        ConfidentialityRequirement::NotDefined
+    }
+}
+
+impl Metric for ConfidentialityRequirement {
+    const TYPE: MetricType = MetricType::CR;
+
+    fn score(self) -> f64 {
+        match self {
+            ConfidentialityRequirement::NotDefined => 1.0,
+            ConfidentialityRequirement::Low => 0.5,
+            ConfidentialityRequirement::Medium => 1.0,
+            ConfidentialityRequirement::High => 1.51,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            ConfidentialityRequirement::NotDefined => "ND",
+            ConfidentialityRequirement::Low => "L",
+            ConfidentialityRequirement::Medium => "M",
+            ConfidentialityRequirement::High => "H",
+        }
+    }
+}
+
+impl fmt::Display for ConfidentialityRequirement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::name(), self.as_str())
+    }
+}
+
+impl FromStr for ConfidentialityRequirement {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "ND" => Ok(ConfidentialityRequirement::NotDefined),
+            "L" => Ok(ConfidentialityRequirement::Low),
+            "M" => Ok(ConfidentialityRequirement::Medium),
+            "H" => Ok(ConfidentialityRequirement::High),
+            _ => Err(Error::InvalidMetric {
+                metric_type: Self::TYPE,
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
@@ -0,0 +1,90 @@
+//! Integrity Requirement (IR)
+
+use crate::{Error, Metric, MetricType, Result};
+use alloc::borrow::ToOwned;
+use core::{fmt, str::FromStr};
+
+/// Integrity Requirement (IR) - CVSS v2.0 Environmental Metric Group
+///
+/// Described in CVSS v2.0 Specification: Section 2.3.1:
+/// <https://www.first.org/cvss/v2/guide#2-3-1-Collateral-Damage-Potential-CDP>
+///
+/// > This metric enables the analyst to customize the CVSS score depending on the importance of
+/// > the affected IT asset to a user's organization, measured in terms of integrity.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum IntegrityRequirement {
+    /// Not Defined (ND)
+    ///
+    /// > Assigning this value to the metric will not influence the score, and is a signal to
+    /// > the equation to skip this metric.
+    NotDefined,
+
+    /// Low (L)
+    ///
+    /// > Loss of integrity is likely to have only a limited adverse effect on the organization
+    /// > or individuals associated with the organization (e.g., employees, customers).
+    Low,
+
+    /// Medium (M)
+    ///
+    /// > Loss of integrity is likely to have a serious adverse effect on the organization or
+    /// > individuals associated with the organization (e.g., employees, customers).
+    Medium,
+
+    /// High (H)
+    ///
+    /// > Loss of integrity is likely to have a catastrophic adverse effect on the organization
+    /// > or individuals associated with the organization (e.g., employees, customers).
+    High,
+}
+
+impl Default for IntegrityRequirement {
+    fn default() -> IntegrityRequirement {
+        IntegrityRequirement::NotDefined
+    }
+}
+
+impl Metric for IntegrityRequirement {
+    const TYPE: MetricType = MetricType::IR;
+
+    fn score(self) -> f64 {
+        match self {
+            IntegrityRequirement::NotDefined => 1.0,
+            IntegrityRequirement::Low => 0.5,
+            IntegrityRequirement::Medium => 1.0,
+            IntegrityRequirement::High => 1.51,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            IntegrityRequirement::NotDefined => "ND",
+            IntegrityRequirement::Low => "L",
+            IntegrityRequirement::Medium => "M",
+            IntegrityRequirement::High => "H",
+        }
+    }
+}
+
+impl fmt::Display for IntegrityRequirement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::name(), self.as_str())
+    }
+}
+
+impl FromStr for IntegrityRequirement {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "ND" => Ok(IntegrityRequirement::NotDefined),
+            "L" => Ok(IntegrityRequirement::Low),
+            "M" => Ok(IntegrityRequirement::Medium),
+            "H" => Ok(IntegrityRequirement::High),
+            _ => Err(Error::InvalidMetric {
+                metric_type: Self::TYPE,
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
@@ -0,0 +1,58 @@
+//! CVSS v2.0 scores
+
+use core::fmt;
+
+/// A CVSS v2.0 score: a number ranging from 0.0 through 10.0, with one
+/// decimal place of precision.
+///
+/// Unlike CVSS v3.x/v4.0, which round up to the nearest tenth, CVSS v2.0
+/// uses ordinary round-half-up rounding to one decimal place.
+///
+/// Described in CVSS v2.0 Specification: Section 3.2.1:
+/// <https://www.first.org/cvss/v2/guide#3-2-1-Base-Equation>
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+pub struct Score(f64);
+
+impl Score {
+    /// Create a new [`Score`] from the given value
+    pub fn new(num: f64) -> Self {
+        Score(num)
+    }
+
+    /// Round this score to one decimal place, using the standard
+    /// round-half-up rule used throughout the CVSS v2.0 specification.
+    pub fn round(self) -> Self {
+        Score((self.0 * 10.0).round() / 10.0)
+    }
+
+    /// Get the numerical value of this score
+    pub fn value(self) -> f64 {
+        self.0
+    }
+
+    /// Get the Qualitative Severity Rating for this score.
+    ///
+    /// Described in CVSS v2.0 Specification: Section 3.4:
+    /// <https://www.first.org/cvss/v2/guide#3-4-Qualitative-Severity-Rating-Scale>
+    pub fn severity(self) -> &'static str {
+        if self.0 < 4.0 {
+            "Low"
+        } else if self.0 < 7.0 {
+            "Medium"
+        } else {
+            "High"
+        }
+    }
+}
+
+impl From<f64> for Score {
+    fn from(num: f64) -> Score {
+        Score(num)
+    }
+}
+
+impl fmt::Display for Score {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.1}", self.0)
+    }
+}
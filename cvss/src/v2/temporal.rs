@@ -0,0 +1,148 @@
+//! CVSS v2.0 Temporal Metric Group
+
+pub mod e;
+pub mod rc;
+pub mod rl;
+
+pub use self::{e::Exploitability, rc::ReportConfidence, rl::RemediationLevel};
+
+use super::{Base, Score};
+use crate::{Error, Metric, MetricType, Result};
+use alloc::{borrow::ToOwned, string::ToString, vec::Vec};
+use core::{fmt, str::FromStr};
+
+#[cfg(feature = "serde")]
+use {
+    alloc::string::String,
+    serde::{de, ser, Deserialize, Serialize},
+};
+
+/// CVSS v2.0 Temporal Metric Group
+///
+/// Described in CVSS v2.0 Specification: Section 2.2:
+/// <https://www.first.org/cvss/v2/guide#2-2-Temporal-Metrics>
+///
+/// > These metrics measure the current state of exploit techniques or code availability, the
+/// > existence of any patches or workarounds, or the confidence that one has in the description
+/// > of a vulnerability.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Temporal {
+    /// Exploitability (E)
+    pub e: Option<Exploitability>,
+
+    /// Remediation Level (RL)
+    pub rl: Option<RemediationLevel>,
+
+    /// Report Confidence (RC)
+    pub rc: Option<ReportConfidence>,
+}
+
+impl Temporal {
+    /// Calculate the Temporal CVSS score, given the Base score it modifies.
+    ///
+    /// Described in CVSS v2.0 Specification: Section 3.3.1:
+    /// <https://www.first.org/cvss/v2/guide#3-3-1-Temporal-Equation>
+    ///
+    /// > The Temporal equation is: `Round_to_1_decimal(BaseScore × Exploitability ×
+    /// > RemediationLevel × ReportConfidence)`.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn score(&self, base: &Base) -> Score {
+        let e = self.e.unwrap_or_default().score();
+        let rl = self.rl.unwrap_or_default().score();
+        let rc = self.rc.unwrap_or_default().score();
+
+        Score::new(base.score().value() * e * rl * rc).round()
+    }
+
+    /// Calculate the Temporal Severity according to the Qualitative
+    /// Severity Rating Scale (i.e. Low / Medium / High)
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn severity(&self, base: &Base) -> &'static str {
+        self.score(base).severity()
+    }
+}
+
+impl fmt::Display for Temporal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut metrics = [self.e.map(|m| m.to_string()), self.rl.map(|m| m.to_string()), self.rc.map(|m| m.to_string())]
+            .into_iter()
+            .flatten();
+
+        if let Some(metric) = metrics.next() {
+            write!(f, "{metric}")?;
+        }
+
+        for metric in metrics {
+            write!(f, "/{metric}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Temporal {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut metrics = Self::default();
+
+        for component in s.split('/') {
+            let mut parts = component.split(':');
+
+            let id = parts.next().ok_or_else(|| Error::InvalidComponent {
+                component: component.to_owned(),
+            })?;
+
+            let value = parts.next().ok_or_else(|| Error::InvalidComponent {
+                component: component.to_owned(),
+            })?;
+
+            if parts.next().is_some() {
+                return Err(Error::InvalidComponent {
+                    component: component.to_owned(),
+                });
+            }
+
+            let id = id.to_ascii_uppercase();
+            let value = value.to_ascii_uppercase();
+
+            match id.parse::<MetricType>()? {
+                MetricType::E => metrics.e = Some(value.parse()?),
+                MetricType::RL => metrics.rl = Some(value.parse()?),
+                MetricType::RC => metrics.rc = Some(value.parse()?),
+                other => {
+                    return Err(Error::UnknownMetric {
+                        name: other.to_string(),
+                    })
+                }
+            }
+        }
+
+        Ok(metrics)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Temporal {
+    fn deserialize<D: de::Deserializer<'de>>(
+        deserializer: D,
+    ) -> core::result::Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl Serialize for Temporal {
+    fn serialize<S: ser::Serializer>(
+        &self,
+        serializer: S,
+    ) -> core::result::Result<S::Ok, S::Error> {
+        self.to_string().serialize(serializer)
+    }
+}
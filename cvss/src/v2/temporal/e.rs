@@ -0,0 +1,97 @@
+//! Exploitability (E)
+
+use crate::{Error, Metric, MetricType, Result};
+use alloc::borrow::ToOwned;
+use core::{fmt, str::FromStr};
+
+/// Exploitability (E) - CVSS v2.0 Temporal Metric Group
+///
+/// Described in CVSS v2.0 Specification: Section 2.2.1:
+/// <https://www.first.org/cvss/v2/guide#2-2-1-Exploitability-E>
+///
+/// > This metric measures the current state of exploit techniques or code availability.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum Exploitability {
+    /// Not Defined (ND)
+    ///
+    /// > Assigning this value to the metric will not influence the score. It is a signal to
+    /// > the equation to skip this metric.
+    NotDefined,
+
+    /// High (H)
+    ///
+    /// > Functional autonomous code exists, or no exploit is required (manual trigger) and
+    /// > details are widely available.
+    High,
+
+    /// Functional (F)
+    ///
+    /// > Functional exploit code is available. The code works in most situations where the
+    /// > vulnerability exists.
+    Functional,
+
+    /// Proof-of-Concept (POC)
+    ///
+    /// > Proof-of-concept exploit code or an attack demonstration that is not practical for
+    /// > most systems is available.
+    ProofOfConcept,
+
+    /// Unproven (U)
+    ///
+    /// > No exploit code is available, or an exploit is entirely theoretical.
+    Unproven,
+}
+
+impl Default for Exploitability {
+    fn default() -> Exploitability {
+        Exploitability::NotDefined
+    }
+}
+
+impl Metric for Exploitability {
+    const TYPE: MetricType = MetricType::E;
+
+    fn score(self) -> f64 {
+        match self {
+            Exploitability::NotDefined => 1.0,
+            Exploitability::High => 1.0,
+            Exploitability::Functional => 0.95,
+            Exploitability::ProofOfConcept => 0.9,
+            Exploitability::Unproven => 0.85,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Exploitability::NotDefined => "ND",
+            Exploitability::High => "H",
+            Exploitability::Functional => "F",
+            Exploitability::ProofOfConcept => "POC",
+            Exploitability::Unproven => "U",
+        }
+    }
+}
+
+impl fmt::Display for Exploitability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::name(), self.as_str())
+    }
+}
+
+impl FromStr for Exploitability {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "ND" => Ok(Exploitability::NotDefined),
+            "H" => Ok(Exploitability::High),
+            "F" => Ok(Exploitability::Functional),
+            "POC" => Ok(Exploitability::ProofOfConcept),
+            "U" => Ok(Exploitability::Unproven),
+            _ => Err(Error::InvalidMetric {
+                metric_type: Self::TYPE,
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
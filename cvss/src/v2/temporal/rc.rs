@@ -0,0 +1,89 @@
+//! Report Confidence (RC)
+
+use crate::{Error, Metric, MetricType, Result};
+use alloc::borrow::ToOwned;
+use core::{fmt, str::FromStr};
+
+/// Report Confidence (RC) - CVSS v2.0 Temporal Metric Group
+///
+/// Described in CVSS v2.0 Specification: Section 2.2.3:
+/// <https://www.first.org/cvss/v2/guide#2-2-3-Report-Confidence-RC>
+///
+/// > This metric measures the degree of confidence in the existence of the vulnerability and
+/// > the credibility of the known technical details.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum ReportConfidence {
+    /// Not Defined (ND)
+    ///
+    /// > Assigning this value to the metric will not influence the score. It is a signal to
+    /// > the equation to skip this metric.
+    NotDefined,
+
+    /// Confirmed (C)
+    ///
+    /// > This vulnerability is confirmed, typically via an unambiguous vendor acknowledgement.
+    Confirmed,
+
+    /// Uncorroborated (UR)
+    ///
+    /// > Multiple non-official sources, possibly including independent security companies or
+    /// > research organizations, have issued a statement about the vulnerability.
+    Uncorroborated,
+
+    /// Unconfirmed (UC)
+    ///
+    /// > A single, unconfirmed source or possibly multiple conflicting reports have reported
+    /// > this vulnerability.
+    Unconfirmed,
+}
+
+impl Default for ReportConfidence {
+    fn default() -> ReportConfidence {
+        ReportConfidence::NotDefined
+    }
+}
+
+impl Metric for ReportConfidence {
+    const TYPE: MetricType = MetricType::RC;
+
+    fn score(self) -> f64 {
+        match self {
+            ReportConfidence::NotDefined => 1.0,
+            ReportConfidence::Confirmed => 1.0,
+            ReportConfidence::Uncorroborated => 0.95,
+            ReportConfidence::Unconfirmed => 0.90,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            ReportConfidence::NotDefined => "ND",
+            ReportConfidence::Confirmed => "C",
+            ReportConfidence::Uncorroborated => "UR",
+            ReportConfidence::Unconfirmed => "UC",
+        }
+    }
+}
+
+impl fmt::Display for ReportConfidence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::name(), self.as_str())
+    }
+}
+
+impl FromStr for ReportConfidence {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "ND" => Ok(ReportConfidence::NotDefined),
+            "C" => Ok(ReportConfidence::Confirmed),
+            "UR" => Ok(ReportConfidence::Uncorroborated),
+            "UC" => Ok(ReportConfidence::Unconfirmed),
+            _ => Err(Error::InvalidMetric {
+                metric_type: Self::TYPE,
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
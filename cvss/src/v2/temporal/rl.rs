@@ -0,0 +1,94 @@
+//! Remediation Level (RL)
+
+use crate::{Error, Metric, MetricType, Result};
+use alloc::borrow::ToOwned;
+use core::{fmt, str::FromStr};
+
+/// Remediation Level (RL) - CVSS v2.0 Temporal Metric Group
+///
+/// Described in CVSS v2.0 Specification: Section 2.2.2:
+/// <https://www.first.org/cvss/v2/guide#2-2-2-Remediation-Level-RL>
+///
+/// > The remediation level of a vulnerability is an important factor for prioritization.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum RemediationLevel {
+    /// Not Defined (ND)
+    ///
+    /// > Assigning this value to the metric will not influence the score. It is a signal to
+    /// > the equation to skip this metric.
+    NotDefined,
+
+    /// Unavailable (U)
+    ///
+    /// > There is either no solution available or it is impossible to apply.
+    Unavailable,
+
+    /// Workaround (W)
+    ///
+    /// > There is an unofficial, non-vendor solution available.
+    Workaround,
+
+    /// Temporary Fix (TF)
+    ///
+    /// > There is an official but temporary fix available.
+    TemporaryFix,
+
+    /// Official Fix (OF)
+    ///
+    /// > A complete vendor solution is available.
+    OfficialFix,
+}
+
+impl Default for RemediationLevel {
+    fn default() -> RemediationLevel {
+        RemediationLevel::NotDefined
+    }
+}
+
+impl Metric for RemediationLevel {
+    const TYPE: MetricType = MetricType::RL;
+
+    fn score(self) -> f64 {
+        match self {
+            RemediationLevel::NotDefined => 1.0,
+            RemediationLevel::Unavailable => 1.0,
+            RemediationLevel::Workaround => 0.95,
+            RemediationLevel::TemporaryFix => 0.90,
+            RemediationLevel::OfficialFix => 0.87,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            RemediationLevel::NotDefined => "ND",
+            RemediationLevel::Unavailable => "U",
+            RemediationLevel::Workaround => "W",
+            RemediationLevel::TemporaryFix => "TF",
+            RemediationLevel::OfficialFix => "OF",
+        }
+    }
+}
+
+impl fmt::Display for RemediationLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::name(), self.as_str())
+    }
+}
+
+impl FromStr for RemediationLevel {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "ND" => Ok(RemediationLevel::NotDefined),
+            "U" => Ok(RemediationLevel::Unavailable),
+            "W" => Ok(RemediationLevel::Workaround),
+            "TF" => Ok(RemediationLevel::TemporaryFix),
+            "OF" => Ok(RemediationLevel::OfficialFix),
+            _ => Err(Error::InvalidMetric {
+                metric_type: Self::TYPE,
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
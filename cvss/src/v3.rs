@@ -2,9 +2,18 @@
 //!
 //! <https://www.first.org/cvss/specification-document>
 
+// NOTE: `Base::to_scores()` (to match `v2::Base`/`v3::Temporal`/`v4::Base`) is not added here.
+// `base.rs`/`score.rs` aren't present in this tree: `base` and `score` below are declared
+// for the `pub use` re-exports that other modules in this crate already depend on, but the
+// Base Metric Group and Score types that would back them are assumed to come from elsewhere
+// and aren't fabricated here.
 pub mod base;
+pub mod environmental;
 pub mod temporal;
 
 mod score;
+mod vector;
 
-pub use self::{base::Base, score::Score};
+pub use self::{
+    base::Base, environmental::Environmental, score::Score, temporal::Temporal, vector::Vector,
+};
@@ -3,32 +3,44 @@
 mod ar;
 mod cr;
 mod ir;
+mod ma;
+mod mac;
+mod mav;
+mod mc;
+mod mi;
+mod mpr;
+mod ms;
+mod mui;
 
 pub use self::{
-    ar::AccessibilityRequirement, cr::ConfidentialityRequirement, ir::IntegrityRequirement,
+    ar::AvailabilityRequirement, cr::ConfidentialityRequirement, ir::IntegrityRequirement,
+    ma::ModifiedAvailabilityImpact, mac::ModifiedAttackComplexity, mav::ModifiedAttackVector,
+    mc::ModifiedConfidentialityImpact, mi::ModifiedIntegrityImpact,
+    mpr::ModifiedPrivilegesRequired, ms::ModifiedScope, mui::ModifiedUserInteraction,
 };
 
-use super::Score;
+use super::{Base, Score};
 use crate::{Error, Metric, MetricType, Result, PREFIX};
-use alloc::{borrow::ToOwned, vec::Vec};
+use alloc::{
+    borrow::ToOwned,
+    string::{String, ToString},
+    vec::Vec,
+};
 use core::{fmt, str::FromStr};
 
 #[cfg(feature = "serde")]
-use {
-    alloc::string::{String, ToString},
-    serde::{de, ser, Deserialize, Serialize},
-};
+use serde::{de, ser, Deserialize, Serialize};
 
 #[cfg(feature = "std")]
 use crate::Severity;
 
 /// CVSS v3.1 Environmental Metric Group
 ///
-/// Described in CVSS v3.1 Specification: Section 2:
+/// Described in CVSS v3.1 Specification: Section 4:
 /// <https://www.first.org/cvss/specification-document#t6>
 ///
 /// > These metrics enable the analyst to customize the CVSS score depending on the importance of the
-/// > affected IT asset to a userâ€™s organization, measured in terms of complementary/alternative security
+/// > affected IT asset to a user's organization, measured in terms of complementary/alternative security
 /// > controls in place, Confidentiality, Integrity, and Availability. The metrics are the modified equivalent
 /// > of Base metrics and are assigned values based on the component placement within organizational
 /// > infrastructure.
@@ -37,106 +49,192 @@ pub struct Environmental {
     /// Minor component of the version
     pub minor_version: usize,
 
-    /// Remediation Level (RL)
-    pub rl: Option<RemediationLevel>,
+    /// Confidentiality Requirement (CR)
+    pub cr: Option<ConfidentialityRequirement>,
+
+    /// Integrity Requirement (IR)
+    pub ir: Option<IntegrityRequirement>,
+
+    /// Availability Requirement (AR)
+    pub ar: Option<AvailabilityRequirement>,
+
+    /// Modified Attack Vector (MAV)
+    pub mav: Option<ModifiedAttackVector>,
+
+    /// Modified Attack Complexity (MAC)
+    pub mac: Option<ModifiedAttackComplexity>,
+
+    /// Modified Privileges Required (MPR)
+    pub mpr: Option<ModifiedPrivilegesRequired>,
+
+    /// Modified User Interaction (MUI)
+    pub mui: Option<ModifiedUserInteraction>,
+
+    /// Modified Scope (MS)
+    pub ms: Option<ModifiedScope>,
+
+    /// Modified Confidentiality Impact (MC)
+    pub mc: Option<ModifiedConfidentialityImpact>,
+
+    /// Modified Integrity Impact (MI)
+    pub mi: Option<ModifiedIntegrityImpact>,
+
+    /// Modified Availability Impact (MA)
+    pub ma: Option<ModifiedAvailabilityImpact>,
 }
 
 impl Environmental {
-    /// Calculate Base CVSS score: overall value for determining the severity
-    /// of a vulnerability, generally referred to as the "CVSS score".
+    /// Calculate the Environmental CVSS score, given the Base metrics it modifies.
     ///
-    /// Described in CVSS v3.1 Specification: Section 2:
+    /// Described in CVSS v3.1 Specification: Section 4:
     /// <https://www.first.org/cvss/specification-document#t6>
-    ///
-    /// > When the Base metrics are assigned values by an analyst, the Base
-    /// > equation computes a score ranging from 0.0 to 10.0.
-    /// >
-    /// > Specifically, the Base equation is derived from two sub equations:
-    /// > the Exploitability sub-score equation, and the Impact sub-score
-    /// > equation. The Exploitability sub-score equation is derived from the
-    /// > Base Exploitability metrics, while the Impact sub-score equation is
-    /// > derived from the Base Impact metrics.
     #[cfg(feature = "std")]
     #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
-    pub fn score(&self) -> Score {
-        let exploitability = self.exploitability().value();
-        let iss = self.impact().value();
+    pub fn score(&self, base: &Base) -> Score {
+        let exploitability = self.exploitability(base).value();
+        let miss = self.modified_impact_subscore(base);
+        let scope_changed = self.is_scope_changed(base);
 
-        let iss_scoped = if !self.is_scope_changed() {
-            6.42 * iss
+        let modified_impact = if !scope_changed {
+            6.42 * miss
         } else {
-            (7.52 * (iss - 0.029)) - (3.25 * (iss - 0.02).powf(15.0))
+            (7.52 * (miss - 0.029)) - (3.25 * (miss * 0.9731 - 0.02).powf(13.0))
         };
 
-        let score = if iss_scoped <= 0.0 {
+        let score = if modified_impact <= 0.0 {
             0.0
-        } else if !self.is_scope_changed() {
-            (iss_scoped + exploitability).min(10.0)
+        } else if !scope_changed {
+            (modified_impact + exploitability).min(10.0)
         } else {
-            (1.08 * (iss_scoped + exploitability)).min(10.0)
+            (1.08 * (modified_impact + exploitability)).min(10.0)
         };
 
         Score::new(score).roundup()
     }
 
-    /// Calculate Base Exploitability score: sub-score for measuring
-    /// ease of exploitation.
-    ///
-    /// Described in CVSS v3.1 Specification: Section 2:
-    /// <https://www.first.org/cvss/specification-document#t6>
-    ///
-    /// > The Exploitability metrics reflect the ease and technical means by which
-    /// > the vulnerability can be exploited. That is, they represent characteristics
-    /// > of *the thing that is vulnerable*, which we refer to formally as the
-    /// > *vulnerable component*.
-    pub fn exploitability(&self) -> Score {
-        let av_score = self.av.map(|av| av.score()).unwrap_or(0.0);
-        let ac_score = self.ac.map(|ac| ac.score()).unwrap_or(0.0);
-        let ui_score = self.ui.map(|ui| ui.score()).unwrap_or(0.0);
-        let pr_score = self
-            .pr
-            .map(|pr| pr.scoped_score(self.is_scope_changed()))
-            .unwrap_or(0.0);
-
-        (8.22 * av_score * ac_score * pr_score * ui_score).into()
+    /// Calculate the Modified Exploitability sub-score.
+    pub fn exploitability(&self, base: &Base) -> Score {
+        let scope_changed = self.is_scope_changed(base);
+
+        let mav = self
+            .mav
+            .unwrap_or_default()
+            .resolve(base.av)
+            .score();
+
+        let mac = self
+            .mac
+            .unwrap_or_default()
+            .resolve(base.ac)
+            .score();
+
+        let mui = self
+            .mui
+            .unwrap_or_default()
+            .resolve(base.ui)
+            .score();
+
+        let mpr = self
+            .mpr
+            .unwrap_or_default()
+            .resolved_score(base.pr, scope_changed);
+
+        (8.22 * mav * mac * mpr * mui).into()
     }
 
-    /// Calculate Base Impact Score (ISS): sub-score for measuring the
-    /// consequences of successful exploitation.
-    ///
-    /// Described in CVSS v3.1 Specification: Section 2:
-    /// <https://www.first.org/cvss/specification-document#t6>
-    ///
-    /// > The Impact metrics reflect the direct consequence
-    /// > of a successful exploit, and represent the consequence to the
-    /// > *thing that suffers the impact*, which we refer to formally as the
-    /// > *impacted component*.
+    /// Calculate the Modified Impact sub-score (MISS), capped at 0.915 as
+    /// per the CVSS v3.1 specification.
+    fn modified_impact_subscore(&self, base: &Base) -> f64 {
+        let mc = self.mc.unwrap_or_default().resolve(base.c).score();
+        let mi = self.mi.unwrap_or_default().resolve(base.i).score();
+        let ma = self.ma.unwrap_or_default().resolve(base.a).score();
+
+        let cr = self.cr.unwrap_or_default().score();
+        let ir = self.ir.unwrap_or_default().score();
+        let ar = self.ar.unwrap_or_default().score();
+
+        (1.0 - ((1.0 - cr * mc) * (1.0 - ir * mi) * (1.0 - ar * ma))).min(0.915)
+    }
+
+    /// Calculate the Modified Impact sub-score.
     #[cfg(feature = "std")]
     #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
-    pub fn impact(&self) -> Score {
-        let c_score = self.c.map(|c| c.score()).unwrap_or(0.0);
-        let i_score = self.i.map(|i| i.score()).unwrap_or(0.0);
-        let a_score = self.a.map(|a| a.score()).unwrap_or(0.0);
-        (1.0 - ((1.0 - c_score) * (1.0 - i_score) * (1.0 - a_score)).abs()).into()
+    pub fn impact(&self, base: &Base) -> Score {
+        self.modified_impact_subscore(base).into()
     }
 
-    /// Calculate Base CVSS `Severity` according to the
+    /// Calculate the Environmental `Severity` according to the
     /// Qualitative Severity Rating Scale (i.e. Low / Medium / High / Critical)
     ///
     /// Described in CVSS v3.1 Specification: Section 5:
     /// <https://www.first.org/cvss/specification-document#t17>
     #[cfg(feature = "std")]
     #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
-    pub fn severity(&self) -> Severity {
-        self.score().severity()
+    pub fn severity(&self, base: &Base) -> Severity {
+        self.score(base).severity()
     }
 
-    /// Has the scope changed?
-    fn is_scope_changed(&self) -> bool {
-        self.s.map(|s| s.is_changed()).unwrap_or(false)
+    /// Has the (Modified) Scope changed?
+    fn is_scope_changed(&self, base: &Base) -> bool {
+        self.ms.unwrap_or_default().is_changed(base.s)
+    }
+
+    /// Are any Environmental metrics set?
+    pub fn has_metrics(&self) -> bool {
+        self.cr.is_some()
+            || self.ir.is_some()
+            || self.ar.is_some()
+            || self.mav.is_some()
+            || self.mac.is_some()
+            || self.mpr.is_some()
+            || self.mui.is_some()
+            || self.ms.is_some()
+            || self.mc.is_some()
+            || self.mi.is_some()
+            || self.ma.is_some()
+    }
+
+    /// Decompose the Environmental score into its constituent parts,
+    /// suitable for storage in structured records (e.g. protobuf/JSON
+    /// schemas) without the consumer having to re-derive them from the
+    /// parsed vector string.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn to_scores(&self, base: &Base) -> Scores {
+        Scores {
+            vector: self.to_string(),
+            base_score: self.score(base).value(),
+            exploitability_score: self.exploitability(base).value(),
+            impact_score: self.impact(base).value(),
+            severity: self.severity(base),
+        }
     }
 }
 
+/// Decomposed CVSS v3.1 Environmental scores.
+///
+/// Returned by [`Environmental::to_scores`].
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Scores {
+    /// Full CVSS v3.1 Environmental vector string
+    pub vector: String,
+
+    /// Overall Environmental score
+    pub base_score: f64,
+
+    /// Modified Exploitability sub-score
+    pub exploitability_score: f64,
+
+    /// Modified Impact sub-score
+    pub impact_score: f64,
+
+    /// Qualitative Severity Rating
+    pub severity: Severity,
+}
+
 macro_rules! write_metrics {
     ($f:expr, $($metric:expr),+) => {
         $(
@@ -150,7 +248,10 @@ macro_rules! write_metrics {
 impl fmt::Display for Environmental {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}:3.{}", PREFIX, self.minor_version)?;
-        write_metrics!(f, self.av, self.ac, self.pr, self.ui, self.s, self.c, self.i, self.a);
+        write_metrics!(
+            f, self.cr, self.ir, self.ar, self.mav, self.mac, self.mpr, self.mui, self.ms,
+            self.mc, self.mi, self.ma
+        );
         Ok(())
     }
 }
@@ -211,7 +312,22 @@ impl FromStr for Environmental {
             let value = component.1.to_ascii_uppercase();
 
             match id.parse::<MetricType>()? {
-                MetricType::E => metrics.e = Some(value.parse()?),
+                MetricType::CR => metrics.cr = Some(value.parse()?),
+                MetricType::IR => metrics.ir = Some(value.parse()?),
+                MetricType::AR => metrics.ar = Some(value.parse()?),
+                MetricType::MAV => metrics.mav = Some(value.parse()?),
+                MetricType::MAC => metrics.mac = Some(value.parse()?),
+                MetricType::MPR => metrics.mpr = Some(value.parse()?),
+                MetricType::MUI => metrics.mui = Some(value.parse()?),
+                MetricType::MS => metrics.ms = Some(value.parse()?),
+                MetricType::MC => metrics.mc = Some(value.parse()?),
+                MetricType::MI => metrics.mi = Some(value.parse()?),
+                MetricType::MA => metrics.ma = Some(value.parse()?),
+                other => {
+                    return Err(Error::UnknownMetric {
+                        name: other.to_string(),
+                    })
+                }
             }
         }
 
@@ -241,3 +357,24 @@ impl Serialize for Environmental {
         self.to_string().serialize(serializer)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_VECTOR: &str =
+        "CVSS:3.1/CR:H/IR:H/AR:H/MAV:N/MAC:L/MPR:N/MUI:N/MS:U/MC:H/MI:H/MA:H";
+
+    #[test]
+    fn parses_and_round_trips() {
+        let environmental: Environmental = EXAMPLE_VECTOR.parse().unwrap();
+        assert_eq!(environmental.to_string(), EXAMPLE_VECTOR);
+    }
+
+    #[test]
+    fn no_metrics_round_trips_to_bare_prefix() {
+        let environmental: Environmental = "CVSS:3.1".parse().unwrap();
+        assert!(!environmental.has_metrics());
+        assert_eq!(environmental.to_string(), "CVSS:3.1");
+    }
+}
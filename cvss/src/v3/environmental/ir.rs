@@ -0,0 +1,89 @@
+//! Integrity Requirement (IR)
+
+use crate::{Error, Metric, MetricType, Result};
+use alloc::borrow::ToOwned;
+use core::{fmt, str::FromStr};
+
+/// Integrity Requirement (IR) - CVSS v3.1 Environmental Metric Group
+///
+/// Described in CVSS v3.1 Specification: Section 4.1:
+/// <https://www.first.org/cvss/v3.1/specification-document#t6>
+///
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum IntegrityRequirement {
+    /// Not Defined (X)
+    ///
+    /// > Assigning this value indicates there is insufficient information to choose
+    /// > one of the other values, and has no impact on the overall Environmental
+    /// > Score, i.e., it has the same effect on scoring as assigning Medium.
+    NotDefined,
+
+    /// High (H)
+    ///
+    /// > Loss of Integrity is likely to have a catastrophic adverse effect on the organization
+    /// > or individuals associated with the organization (e.g., employees, customers).
+    High,
+
+    /// Medium (M)
+    ///
+    /// > Loss of Integrity is likely to have a serious adverse effect on the organization or
+    /// > individuals associated with the organization (e.g., employees, customers).
+    Medium,
+
+    /// Low (L)
+    ///
+    /// > Loss of Integrity is likely to have only a limited adverse effect on the organization
+    /// > or individuals associated with the organization (e.g., employees, customers).
+    Low,
+}
+
+impl Default for IntegrityRequirement {
+    fn default() -> IntegrityRequirement {
+        IntegrityRequirement::NotDefined
+    }
+}
+
+impl Metric for IntegrityRequirement {
+    const TYPE: MetricType = MetricType::IR;
+
+    fn score(self) -> f64 {
+        match self {
+            IntegrityRequirement::NotDefined => 1.0,
+            IntegrityRequirement::High => 1.5,
+            IntegrityRequirement::Medium => 1.0,
+            IntegrityRequirement::Low => 0.5,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            IntegrityRequirement::NotDefined => "X",
+            IntegrityRequirement::High => "H",
+            IntegrityRequirement::Medium => "M",
+            IntegrityRequirement::Low => "L",
+        }
+    }
+}
+
+impl fmt::Display for IntegrityRequirement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::name(), self.as_str())
+    }
+}
+
+impl FromStr for IntegrityRequirement {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "X" => Ok(IntegrityRequirement::NotDefined),
+            "H" => Ok(IntegrityRequirement::High),
+            "M" => Ok(IntegrityRequirement::Medium),
+            "L" => Ok(IntegrityRequirement::Low),
+            _ => Err(Error::InvalidMetric {
+                metric_type: Self::TYPE,
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
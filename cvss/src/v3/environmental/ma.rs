@@ -0,0 +1,91 @@
+//! Modified Availability Impact (MA)
+
+use crate::v3::base::a::Availability;
+use crate::{Error, Metric, MetricType, Result};
+use alloc::borrow::ToOwned;
+use core::{fmt, str::FromStr};
+
+/// Modified Availability Impact (MA) - CVSS v3.1 Environmental Metric Group
+///
+/// Described in CVSS v3.1 Specification: Section 4.2:
+/// <https://www.first.org/cvss/v3.1/specification-document#t6>
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum ModifiedAvailabilityImpact {
+    /// Not Defined (X)
+    ///
+    /// > The value assigned to the corresponding Base metric is used.
+    NotDefined,
+
+    /// High (H)
+    High,
+
+    /// Low (L)
+    Low,
+
+    /// None (N)
+    None,
+}
+
+impl ModifiedAvailabilityImpact {
+    /// Resolve this metric to a concrete value, falling back to the given
+    /// Base [`Availability`] value when this metric is Not Defined.
+    pub fn resolve(self, base: Availability) -> Availability {
+        match self {
+            ModifiedAvailabilityImpact::NotDefined => base,
+            ModifiedAvailabilityImpact::High => Availability::High,
+            ModifiedAvailabilityImpact::Low => Availability::Low,
+            ModifiedAvailabilityImpact::None => Availability::None,
+        }
+    }
+}
+
+impl Default for ModifiedAvailabilityImpact {
+    fn default() -> ModifiedAvailabilityImpact {
+        ModifiedAvailabilityImpact::NotDefined
+    }
+}
+
+impl Metric for ModifiedAvailabilityImpact {
+    const TYPE: MetricType = MetricType::MA;
+
+    fn score(self) -> f64 {
+        match self {
+            ModifiedAvailabilityImpact::NotDefined => 0.0,
+            ModifiedAvailabilityImpact::High => Availability::High.score(),
+            ModifiedAvailabilityImpact::Low => Availability::Low.score(),
+            ModifiedAvailabilityImpact::None => Availability::None.score(),
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            ModifiedAvailabilityImpact::NotDefined => "X",
+            ModifiedAvailabilityImpact::High => "H",
+            ModifiedAvailabilityImpact::Low => "L",
+            ModifiedAvailabilityImpact::None => "N",
+        }
+    }
+}
+
+impl fmt::Display for ModifiedAvailabilityImpact {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::name(), self.as_str())
+    }
+}
+
+impl FromStr for ModifiedAvailabilityImpact {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "X" => Ok(ModifiedAvailabilityImpact::NotDefined),
+            "H" => Ok(ModifiedAvailabilityImpact::High),
+            "L" => Ok(ModifiedAvailabilityImpact::Low),
+            "N" => Ok(ModifiedAvailabilityImpact::None),
+            _ => Err(Error::InvalidMetric {
+                metric_type: Self::TYPE,
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
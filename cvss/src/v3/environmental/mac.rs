@@ -0,0 +1,84 @@
+//! Modified Attack Complexity (MAC)
+
+use crate::v3::base::ac::AttackComplexity;
+use crate::{Error, Metric, MetricType, Result};
+use alloc::borrow::ToOwned;
+use core::{fmt, str::FromStr};
+
+/// Modified Attack Complexity (MAC) - CVSS v3.1 Environmental Metric Group
+///
+/// Described in CVSS v3.1 Specification: Section 4.2:
+/// <https://www.first.org/cvss/v3.1/specification-document#t6>
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum ModifiedAttackComplexity {
+    /// Not Defined (X)
+    ///
+    /// > The value assigned to the corresponding Base metric is used.
+    NotDefined,
+
+    /// High (H)
+    High,
+
+    /// Low (L)
+    Low,
+}
+
+impl ModifiedAttackComplexity {
+    /// Resolve this metric to a concrete value, falling back to the given
+    /// Base [`AttackComplexity`] value when this metric is Not Defined.
+    pub fn resolve(self, base: AttackComplexity) -> AttackComplexity {
+        match self {
+            ModifiedAttackComplexity::NotDefined => base,
+            ModifiedAttackComplexity::High => AttackComplexity::High,
+            ModifiedAttackComplexity::Low => AttackComplexity::Low,
+        }
+    }
+}
+
+impl Default for ModifiedAttackComplexity {
+    fn default() -> ModifiedAttackComplexity {
+        ModifiedAttackComplexity::NotDefined
+    }
+}
+
+impl Metric for ModifiedAttackComplexity {
+    const TYPE: MetricType = MetricType::MAC;
+
+    fn score(self) -> f64 {
+        match self {
+            ModifiedAttackComplexity::NotDefined => 0.0,
+            ModifiedAttackComplexity::High => AttackComplexity::High.score(),
+            ModifiedAttackComplexity::Low => AttackComplexity::Low.score(),
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            ModifiedAttackComplexity::NotDefined => "X",
+            ModifiedAttackComplexity::High => "H",
+            ModifiedAttackComplexity::Low => "L",
+        }
+    }
+}
+
+impl fmt::Display for ModifiedAttackComplexity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::name(), self.as_str())
+    }
+}
+
+impl FromStr for ModifiedAttackComplexity {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "X" => Ok(ModifiedAttackComplexity::NotDefined),
+            "H" => Ok(ModifiedAttackComplexity::High),
+            "L" => Ok(ModifiedAttackComplexity::Low),
+            _ => Err(Error::InvalidMetric {
+                metric_type: Self::TYPE,
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
@@ -0,0 +1,103 @@
+//! Modified Attack Vector (MAV)
+
+use crate::v3::base::av::AttackVector;
+use crate::{Error, Metric, MetricType, Result};
+use alloc::borrow::ToOwned;
+use core::{fmt, str::FromStr};
+
+/// Modified Attack Vector (MAV) - CVSS v3.1 Environmental Metric Group
+///
+/// Described in CVSS v3.1 Specification: Section 4.2:
+/// <https://www.first.org/cvss/v3.1/specification-document#t6>
+///
+/// > These metrics enable the analyst to override individual Base metric values based on
+/// > specific characteristics of a user's environment. Their values cannot modify the
+/// > values of the Base metrics themselves, they simply replace them with new values
+/// > when computing the Environmental Score.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum ModifiedAttackVector {
+    /// Not Defined (X)
+    ///
+    /// > The value assigned to the corresponding Base metric is used.
+    NotDefined,
+
+    /// Physical (P)
+    Physical,
+
+    /// Local (L)
+    Local,
+
+    /// Adjacent (A)
+    Adjacent,
+
+    /// Network (N)
+    Network,
+}
+
+impl ModifiedAttackVector {
+    /// Resolve this metric to a concrete value, falling back to the given
+    /// Base [`AttackVector`] value when this metric is Not Defined.
+    pub fn resolve(self, base: AttackVector) -> AttackVector {
+        match self {
+            ModifiedAttackVector::NotDefined => base,
+            ModifiedAttackVector::Physical => AttackVector::Physical,
+            ModifiedAttackVector::Local => AttackVector::Local,
+            ModifiedAttackVector::Adjacent => AttackVector::Adjacent,
+            ModifiedAttackVector::Network => AttackVector::Network,
+        }
+    }
+}
+
+impl Default for ModifiedAttackVector {
+    fn default() -> ModifiedAttackVector {
+        ModifiedAttackVector::NotDefined
+    }
+}
+
+impl Metric for ModifiedAttackVector {
+    const TYPE: MetricType = MetricType::MAV;
+
+    fn score(self) -> f64 {
+        match self {
+            ModifiedAttackVector::NotDefined => 0.0,
+            ModifiedAttackVector::Physical => AttackVector::Physical.score(),
+            ModifiedAttackVector::Local => AttackVector::Local.score(),
+            ModifiedAttackVector::Adjacent => AttackVector::Adjacent.score(),
+            ModifiedAttackVector::Network => AttackVector::Network.score(),
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            ModifiedAttackVector::NotDefined => "X",
+            ModifiedAttackVector::Physical => "P",
+            ModifiedAttackVector::Local => "L",
+            ModifiedAttackVector::Adjacent => "A",
+            ModifiedAttackVector::Network => "N",
+        }
+    }
+}
+
+impl fmt::Display for ModifiedAttackVector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::name(), self.as_str())
+    }
+}
+
+impl FromStr for ModifiedAttackVector {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "X" => Ok(ModifiedAttackVector::NotDefined),
+            "P" => Ok(ModifiedAttackVector::Physical),
+            "L" => Ok(ModifiedAttackVector::Local),
+            "A" => Ok(ModifiedAttackVector::Adjacent),
+            "N" => Ok(ModifiedAttackVector::Network),
+            _ => Err(Error::InvalidMetric {
+                metric_type: Self::TYPE,
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
@@ -0,0 +1,91 @@
+//! Modified Confidentiality Impact (MC)
+
+use crate::v3::base::c::Confidentiality;
+use crate::{Error, Metric, MetricType, Result};
+use alloc::borrow::ToOwned;
+use core::{fmt, str::FromStr};
+
+/// Modified Confidentiality Impact (MC) - CVSS v3.1 Environmental Metric Group
+///
+/// Described in CVSS v3.1 Specification: Section 4.2:
+/// <https://www.first.org/cvss/v3.1/specification-document#t6>
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum ModifiedConfidentialityImpact {
+    /// Not Defined (X)
+    ///
+    /// > The value assigned to the corresponding Base metric is used.
+    NotDefined,
+
+    /// High (H)
+    High,
+
+    /// Low (L)
+    Low,
+
+    /// None (N)
+    None,
+}
+
+impl ModifiedConfidentialityImpact {
+    /// Resolve this metric to a concrete value, falling back to the given
+    /// Base [`Confidentiality`] value when this metric is Not Defined.
+    pub fn resolve(self, base: Confidentiality) -> Confidentiality {
+        match self {
+            ModifiedConfidentialityImpact::NotDefined => base,
+            ModifiedConfidentialityImpact::High => Confidentiality::High,
+            ModifiedConfidentialityImpact::Low => Confidentiality::Low,
+            ModifiedConfidentialityImpact::None => Confidentiality::None,
+        }
+    }
+}
+
+impl Default for ModifiedConfidentialityImpact {
+    fn default() -> ModifiedConfidentialityImpact {
+        ModifiedConfidentialityImpact::NotDefined
+    }
+}
+
+impl Metric for ModifiedConfidentialityImpact {
+    const TYPE: MetricType = MetricType::MC;
+
+    fn score(self) -> f64 {
+        match self {
+            ModifiedConfidentialityImpact::NotDefined => 0.0,
+            ModifiedConfidentialityImpact::High => Confidentiality::High.score(),
+            ModifiedConfidentialityImpact::Low => Confidentiality::Low.score(),
+            ModifiedConfidentialityImpact::None => Confidentiality::None.score(),
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            ModifiedConfidentialityImpact::NotDefined => "X",
+            ModifiedConfidentialityImpact::High => "H",
+            ModifiedConfidentialityImpact::Low => "L",
+            ModifiedConfidentialityImpact::None => "N",
+        }
+    }
+}
+
+impl fmt::Display for ModifiedConfidentialityImpact {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::name(), self.as_str())
+    }
+}
+
+impl FromStr for ModifiedConfidentialityImpact {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "X" => Ok(ModifiedConfidentialityImpact::NotDefined),
+            "H" => Ok(ModifiedConfidentialityImpact::High),
+            "L" => Ok(ModifiedConfidentialityImpact::Low),
+            "N" => Ok(ModifiedConfidentialityImpact::None),
+            _ => Err(Error::InvalidMetric {
+                metric_type: Self::TYPE,
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
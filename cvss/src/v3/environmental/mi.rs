@@ -0,0 +1,91 @@
+//! Modified Integrity Impact (MI)
+
+use crate::v3::base::i::Integrity;
+use crate::{Error, Metric, MetricType, Result};
+use alloc::borrow::ToOwned;
+use core::{fmt, str::FromStr};
+
+/// Modified Integrity Impact (MI) - CVSS v3.1 Environmental Metric Group
+///
+/// Described in CVSS v3.1 Specification: Section 4.2:
+/// <https://www.first.org/cvss/v3.1/specification-document#t6>
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum ModifiedIntegrityImpact {
+    /// Not Defined (X)
+    ///
+    /// > The value assigned to the corresponding Base metric is used.
+    NotDefined,
+
+    /// High (H)
+    High,
+
+    /// Low (L)
+    Low,
+
+    /// None (N)
+    None,
+}
+
+impl ModifiedIntegrityImpact {
+    /// Resolve this metric to a concrete value, falling back to the given
+    /// Base [`Integrity`] value when this metric is Not Defined.
+    pub fn resolve(self, base: Integrity) -> Integrity {
+        match self {
+            ModifiedIntegrityImpact::NotDefined => base,
+            ModifiedIntegrityImpact::High => Integrity::High,
+            ModifiedIntegrityImpact::Low => Integrity::Low,
+            ModifiedIntegrityImpact::None => Integrity::None,
+        }
+    }
+}
+
+impl Default for ModifiedIntegrityImpact {
+    fn default() -> ModifiedIntegrityImpact {
+        ModifiedIntegrityImpact::NotDefined
+    }
+}
+
+impl Metric for ModifiedIntegrityImpact {
+    const TYPE: MetricType = MetricType::MI;
+
+    fn score(self) -> f64 {
+        match self {
+            ModifiedIntegrityImpact::NotDefined => 0.0,
+            ModifiedIntegrityImpact::High => Integrity::High.score(),
+            ModifiedIntegrityImpact::Low => Integrity::Low.score(),
+            ModifiedIntegrityImpact::None => Integrity::None.score(),
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            ModifiedIntegrityImpact::NotDefined => "X",
+            ModifiedIntegrityImpact::High => "H",
+            ModifiedIntegrityImpact::Low => "L",
+            ModifiedIntegrityImpact::None => "N",
+        }
+    }
+}
+
+impl fmt::Display for ModifiedIntegrityImpact {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::name(), self.as_str())
+    }
+}
+
+impl FromStr for ModifiedIntegrityImpact {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "X" => Ok(ModifiedIntegrityImpact::NotDefined),
+            "H" => Ok(ModifiedIntegrityImpact::High),
+            "L" => Ok(ModifiedIntegrityImpact::Low),
+            "N" => Ok(ModifiedIntegrityImpact::None),
+            _ => Err(Error::InvalidMetric {
+                metric_type: Self::TYPE,
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
@@ -0,0 +1,97 @@
+//! Modified Privileges Required (MPR)
+
+use crate::v3::base::pr::PrivilegesRequired;
+use crate::{Error, Metric, MetricType, Result};
+use alloc::borrow::ToOwned;
+use core::{fmt, str::FromStr};
+
+/// Modified Privileges Required (MPR) - CVSS v3.1 Environmental Metric Group
+///
+/// Described in CVSS v3.1 Specification: Section 4.2:
+/// <https://www.first.org/cvss/v3.1/specification-document#t6>
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum ModifiedPrivilegesRequired {
+    /// Not Defined (X)
+    ///
+    /// > The value assigned to the corresponding Base metric is used.
+    NotDefined,
+
+    /// High (H)
+    High,
+
+    /// Low (L)
+    Low,
+
+    /// None (N)
+    None,
+}
+
+impl ModifiedPrivilegesRequired {
+    /// Resolve this metric to a concrete value, falling back to the given
+    /// Base [`PrivilegesRequired`] value when this metric is Not Defined.
+    pub fn resolve(self, base: PrivilegesRequired) -> PrivilegesRequired {
+        match self {
+            ModifiedPrivilegesRequired::NotDefined => base,
+            ModifiedPrivilegesRequired::High => PrivilegesRequired::High,
+            ModifiedPrivilegesRequired::Low => PrivilegesRequired::Low,
+            ModifiedPrivilegesRequired::None => PrivilegesRequired::None,
+        }
+    }
+
+    /// Score this metric, using the scope-adjusted values based on whether
+    /// the (Modified) Scope is Changed.
+    pub fn resolved_score(self, base: PrivilegesRequired, scope_changed: bool) -> f64 {
+        self.resolve(base).scoped_score(scope_changed)
+    }
+}
+
+impl Default for ModifiedPrivilegesRequired {
+    fn default() -> ModifiedPrivilegesRequired {
+        ModifiedPrivilegesRequired::NotDefined
+    }
+}
+
+impl Metric for ModifiedPrivilegesRequired {
+    const TYPE: MetricType = MetricType::MPR;
+
+    fn score(self) -> f64 {
+        match self {
+            ModifiedPrivilegesRequired::NotDefined => 0.0,
+            ModifiedPrivilegesRequired::High => PrivilegesRequired::High.scoped_score(false),
+            ModifiedPrivilegesRequired::Low => PrivilegesRequired::Low.scoped_score(false),
+            ModifiedPrivilegesRequired::None => PrivilegesRequired::None.scoped_score(false),
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            ModifiedPrivilegesRequired::NotDefined => "X",
+            ModifiedPrivilegesRequired::High => "H",
+            ModifiedPrivilegesRequired::Low => "L",
+            ModifiedPrivilegesRequired::None => "N",
+        }
+    }
+}
+
+impl fmt::Display for ModifiedPrivilegesRequired {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::name(), self.as_str())
+    }
+}
+
+impl FromStr for ModifiedPrivilegesRequired {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "X" => Ok(ModifiedPrivilegesRequired::NotDefined),
+            "H" => Ok(ModifiedPrivilegesRequired::High),
+            "L" => Ok(ModifiedPrivilegesRequired::Low),
+            "N" => Ok(ModifiedPrivilegesRequired::None),
+            _ => Err(Error::InvalidMetric {
+                metric_type: Self::TYPE,
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
@@ -0,0 +1,85 @@
+//! Modified Scope (MS)
+
+use crate::v3::base::s::Scope;
+use crate::{Error, Metric, MetricType, Result};
+use alloc::borrow::ToOwned;
+use core::{fmt, str::FromStr};
+
+/// Modified Scope (MS) - CVSS v3.1 Environmental Metric Group
+///
+/// Described in CVSS v3.1 Specification: Section 4.2:
+/// <https://www.first.org/cvss/v3.1/specification-document#t6>
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum ModifiedScope {
+    /// Not Defined (X)
+    ///
+    /// > The value assigned to the corresponding Base metric is used.
+    NotDefined,
+
+    /// Unchanged (U)
+    Unchanged,
+
+    /// Changed (C)
+    Changed,
+}
+
+impl ModifiedScope {
+    /// Resolve this metric to a concrete value, falling back to the given
+    /// Base [`Scope`] value when this metric is Not Defined.
+    pub fn resolve(self, base: Scope) -> Scope {
+        match self {
+            ModifiedScope::NotDefined => base,
+            ModifiedScope::Unchanged => Scope::Unchanged,
+            ModifiedScope::Changed => Scope::Changed,
+        }
+    }
+
+    /// Has the (resolved) scope changed?
+    pub fn is_changed(self, base: Scope) -> bool {
+        self.resolve(base).is_changed()
+    }
+}
+
+impl Default for ModifiedScope {
+    fn default() -> ModifiedScope {
+        ModifiedScope::NotDefined
+    }
+}
+
+impl Metric for ModifiedScope {
+    const TYPE: MetricType = MetricType::MS;
+
+    fn score(self) -> f64 {
+        0.0
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            ModifiedScope::NotDefined => "X",
+            ModifiedScope::Unchanged => "U",
+            ModifiedScope::Changed => "C",
+        }
+    }
+}
+
+impl fmt::Display for ModifiedScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::name(), self.as_str())
+    }
+}
+
+impl FromStr for ModifiedScope {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "X" => Ok(ModifiedScope::NotDefined),
+            "U" => Ok(ModifiedScope::Unchanged),
+            "C" => Ok(ModifiedScope::Changed),
+            _ => Err(Error::InvalidMetric {
+                metric_type: Self::TYPE,
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
@@ -0,0 +1,84 @@
+//! Modified User Interaction (MUI)
+
+use crate::v3::base::ui::UserInteraction;
+use crate::{Error, Metric, MetricType, Result};
+use alloc::borrow::ToOwned;
+use core::{fmt, str::FromStr};
+
+/// Modified User Interaction (MUI) - CVSS v3.1 Environmental Metric Group
+///
+/// Described in CVSS v3.1 Specification: Section 4.2:
+/// <https://www.first.org/cvss/v3.1/specification-document#t6>
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum ModifiedUserInteraction {
+    /// Not Defined (X)
+    ///
+    /// > The value assigned to the corresponding Base metric is used.
+    NotDefined,
+
+    /// None (N)
+    None,
+
+    /// Required (R)
+    Required,
+}
+
+impl ModifiedUserInteraction {
+    /// Resolve this metric to a concrete value, falling back to the given
+    /// Base [`UserInteraction`] value when this metric is Not Defined.
+    pub fn resolve(self, base: UserInteraction) -> UserInteraction {
+        match self {
+            ModifiedUserInteraction::NotDefined => base,
+            ModifiedUserInteraction::None => UserInteraction::None,
+            ModifiedUserInteraction::Required => UserInteraction::Required,
+        }
+    }
+}
+
+impl Default for ModifiedUserInteraction {
+    fn default() -> ModifiedUserInteraction {
+        ModifiedUserInteraction::NotDefined
+    }
+}
+
+impl Metric for ModifiedUserInteraction {
+    const TYPE: MetricType = MetricType::MUI;
+
+    fn score(self) -> f64 {
+        match self {
+            ModifiedUserInteraction::NotDefined => 0.0,
+            ModifiedUserInteraction::None => UserInteraction::None.score(),
+            ModifiedUserInteraction::Required => UserInteraction::Required.score(),
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            ModifiedUserInteraction::NotDefined => "X",
+            ModifiedUserInteraction::None => "N",
+            ModifiedUserInteraction::Required => "R",
+        }
+    }
+}
+
+impl fmt::Display for ModifiedUserInteraction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::name(), self.as_str())
+    }
+}
+
+impl FromStr for ModifiedUserInteraction {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "X" => Ok(ModifiedUserInteraction::NotDefined),
+            "N" => Ok(ModifiedUserInteraction::None),
+            "R" => Ok(ModifiedUserInteraction::Required),
+            _ => Err(Error::InvalidMetric {
+                metric_type: Self::TYPE,
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
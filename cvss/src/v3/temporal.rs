@@ -0,0 +1,247 @@
+//! CVSS v3.1 Temporal Metric Group
+
+mod e;
+mod rc;
+mod rl;
+
+pub use self::{e::ExploitCodeMaturity, rc::ReportConfidence, rl::RemediationLevel};
+
+use super::{Base, Score};
+use crate::{Error, Metric, MetricType, Result, PREFIX};
+use alloc::{
+    borrow::ToOwned,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{fmt, str::FromStr};
+
+#[cfg(feature = "serde")]
+use serde::{de, ser, Deserialize, Serialize};
+
+#[cfg(feature = "std")]
+use crate::Severity;
+
+/// CVSS v3.1 Temporal Metric Group
+///
+/// Described in CVSS v3.1 Specification: Section 3:
+/// <https://www.first.org/cvss/specification-document#t6>
+///
+/// > These metrics measure the current state of exploit techniques or code availability, the
+/// > existence of any patches or workarounds, or the confidence that one has in the description
+/// > of a vulnerability. Temporal metrics will change over time.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Temporal {
+    /// Minor component of the version
+    pub minor_version: usize,
+
+    /// Exploit Code Maturity (E)
+    pub e: Option<ExploitCodeMaturity>,
+
+    /// Remediation Level (RL)
+    pub rl: Option<RemediationLevel>,
+
+    /// Report Confidence (RC)
+    pub rc: Option<ReportConfidence>,
+}
+
+impl Temporal {
+    /// Calculate the Temporal CVSS score, given the Base score it modifies.
+    ///
+    /// Described in CVSS v3.1 Specification: Section 3:
+    /// <https://www.first.org/cvss/specification-document#t6>
+    ///
+    /// > The Temporal metrics equation is: `Roundup (BaseScore × ExploitCodeMaturity ×
+    /// > RemediationLevel × ReportConfidence)`.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn score(&self, base: &Base) -> Score {
+        let e = self.e.unwrap_or_default().score();
+        let rl = self.rl.unwrap_or_default().score();
+        let rc = self.rc.unwrap_or_default().score();
+
+        Score::new(base.score().value() * e * rl * rc).roundup()
+    }
+
+    /// Calculate the Temporal `Severity` according to the
+    /// Qualitative Severity Rating Scale (i.e. Low / Medium / High / Critical)
+    ///
+    /// Described in CVSS v3.1 Specification: Section 5:
+    /// <https://www.first.org/cvss/specification-document#t17>
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn severity(&self, base: &Base) -> Severity {
+        self.score(base).severity()
+    }
+
+    /// Are any Temporal metrics set?
+    pub fn has_metrics(&self) -> bool {
+        self.e.is_some() || self.rl.is_some() || self.rc.is_some()
+    }
+
+    /// Decompose the Temporal score into its constituent parts, suitable for
+    /// storage in structured records (e.g. protobuf/JSON schemas) without
+    /// the consumer having to re-derive them from the parsed vector string.
+    ///
+    /// Unlike [`super::Environmental::to_scores`], the Temporal metric group
+    /// has no Exploitability/Impact sub-scores of its own (see
+    /// [`Temporal::score`]), so this one carries only the overall score.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn to_scores(&self, base: &Base) -> Scores {
+        Scores {
+            vector: self.to_string(),
+            base_score: self.score(base).value(),
+            severity: self.severity(base),
+        }
+    }
+}
+
+/// Decomposed CVSS v3.1 Temporal scores.
+///
+/// Returned by [`Temporal::to_scores`].
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Scores {
+    /// Full CVSS v3.1 Temporal vector string
+    pub vector: String,
+
+    /// Overall Temporal score
+    pub base_score: f64,
+
+    /// Qualitative Severity Rating
+    pub severity: Severity,
+}
+
+macro_rules! write_metrics {
+    ($f:expr, $($metric:expr),+) => {
+        $(
+            if let Some(metric) = $metric {
+                write!($f, "/{}", metric)?;
+            }
+        )+
+    };
+}
+
+impl fmt::Display for Temporal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:3.{}", PREFIX, self.minor_version)?;
+        write_metrics!(f, self.e, self.rl, self.rc);
+        Ok(())
+    }
+}
+
+impl FromStr for Temporal {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let component_vec = s
+            .split('/')
+            .map(|component| {
+                let mut parts = component.split(':');
+
+                let id = parts.next().ok_or_else(|| Error::InvalidComponent {
+                    component: component.to_owned(),
+                })?;
+
+                let value = parts.next().ok_or_else(|| Error::InvalidComponent {
+                    component: component.to_owned(),
+                })?;
+
+                if parts.next().is_some() {
+                    return Err(Error::InvalidComponent {
+                        component: component.to_owned(),
+                    });
+                }
+
+                Ok((id, value))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut components = component_vec.iter();
+        let &(id, version_string) = components.next().ok_or(Error::InvalidPrefix {
+            prefix: s.to_owned(),
+        })?;
+
+        if id != PREFIX {
+            return Err(Error::InvalidPrefix {
+                prefix: id.to_owned(),
+            });
+        }
+
+        let mut metrics = Self {
+            minor_version: match version_string {
+                "3.0" => 0,
+                "3.1" => 1,
+                _ => {
+                    return Err(Error::UnsupportedVersion {
+                        version: version_string.to_owned(),
+                    })
+                }
+            },
+            ..Default::default()
+        };
+
+        for &component in components {
+            let id = component.0.to_ascii_uppercase();
+            let value = component.1.to_ascii_uppercase();
+
+            match id.parse::<MetricType>()? {
+                MetricType::E => metrics.e = Some(value.parse()?),
+                MetricType::RL => metrics.rl = Some(value.parse()?),
+                MetricType::RC => metrics.rc = Some(value.parse()?),
+                other => {
+                    return Err(Error::UnknownMetric {
+                        name: other.to_string(),
+                    })
+                }
+            }
+        }
+
+        Ok(metrics)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Temporal {
+    fn deserialize<D: de::Deserializer<'de>>(
+        deserializer: D,
+    ) -> core::result::Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl Serialize for Temporal {
+    fn serialize<S: ser::Serializer>(
+        &self,
+        serializer: S,
+    ) -> core::result::Result<S::Ok, S::Error> {
+        self.to_string().serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_VECTOR: &str = "CVSS:3.1/E:F/RL:O/RC:C";
+
+    #[test]
+    fn parses_and_round_trips() {
+        let temporal: Temporal = EXAMPLE_VECTOR.parse().unwrap();
+        assert_eq!(temporal.to_string(), EXAMPLE_VECTOR);
+    }
+
+    #[test]
+    fn no_metrics_round_trips_to_bare_prefix() {
+        let temporal: Temporal = "CVSS:3.1".parse().unwrap();
+        assert!(!temporal.has_metrics());
+        assert_eq!(temporal.to_string(), "CVSS:3.1");
+    }
+}
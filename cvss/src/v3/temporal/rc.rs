@@ -0,0 +1,105 @@
+//! Report Confidence (RC)
+
+use crate::{Error, Metric, MetricType, Result};
+use alloc::borrow::ToOwned;
+use core::{fmt, str::FromStr};
+
+/// Report Confidence (RC) - CVSS v3.1 Temporal Metric Group
+///
+/// Described in CVSS v3.1 Specification: Section 3.3:
+/// <https://www.first.org/cvss/v3.1/specification-document#t6>
+///
+/// > This metric measures the degree of confidence in the existence of the vulnerability and the
+/// > credibility of the known technical details. Sometimes, only the existence of vulnerabilities
+/// > are publicized, but without specific details. For example, an impact may be recognized as
+/// > undesirable, but the root cause may not be known. The vulnerability may later be corroborated
+/// > by research which suggests where the vulnerability may lie, though the research may not be
+/// > certain. Finally, a vulnerability may be confirmed through acknowledgement by the author or
+/// > vendor of the affected technology.
+/// >
+/// > The more a vulnerability is validated by the vendor or other reputable sources, the higher
+/// > the score.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum ReportConfidence {
+    /// Not Defined (X)
+    ///
+    /// > Assigning this value indicates there is insufficient information to choose one of the
+    /// > other values, and has no impact on the overall Temporal Score, i.e., it has the same
+    /// > effect on scoring as assigning Confirmed.
+    NotDefined,
+
+    /// Confirmed (C)
+    ///
+    /// > Detailed reports exist, or functional reproduction is possible (functional exploits may
+    /// > provide this). Source code is available to independently confirm the maliciousness of
+    /// > the behavior, or the vendor or author of the affected technology has confirmed the
+    /// > presence of the vulnerability.
+    Confirmed,
+
+    /// Reasonable (R)
+    ///
+    /// > Significant details are published, but researchers either do not have full confidence in
+    /// > the root cause, or do not have access to source code to fully confirm all of the
+    /// > interactions that may lead to the result. Reasonable confidence exists, however, that the
+    /// > bug is reproducible and at least one impact is able to be verified (proof-of-concept
+    /// > exploits may provide this).
+    Reasonable,
+
+    /// Unknown (U)
+    ///
+    /// > There are reports of impacts that indicate a vulnerability is present. The reports
+    /// > indicate that the cause of the vulnerability is unknown, or reports may differ on the
+    /// > cause or impacts of the vulnerability.
+    Unknown,
+}
+
+impl Default for ReportConfidence {
+    fn default() -> ReportConfidence {
+        ReportConfidence::NotDefined
+    }
+}
+
+impl Metric for ReportConfidence {
+    const TYPE: MetricType = MetricType::RC;
+
+    fn score(self) -> f64 {
+        match self {
+            ReportConfidence::NotDefined => 1.0,
+            ReportConfidence::Confirmed => 1.0,
+            ReportConfidence::Reasonable => 0.96,
+            ReportConfidence::Unknown => 0.92,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            ReportConfidence::NotDefined => "X",
+            ReportConfidence::Confirmed => "C",
+            ReportConfidence::Reasonable => "R",
+            ReportConfidence::Unknown => "U",
+        }
+    }
+}
+
+impl fmt::Display for ReportConfidence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::name(), self.as_str())
+    }
+}
+
+impl FromStr for ReportConfidence {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "X" => Ok(ReportConfidence::NotDefined),
+            "C" => Ok(ReportConfidence::Confirmed),
+            "R" => Ok(ReportConfidence::Reasonable),
+            "U" => Ok(ReportConfidence::Unknown),
+            _ => Err(Error::InvalidMetric {
+                metric_type: Self::TYPE,
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
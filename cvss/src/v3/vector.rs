@@ -0,0 +1,148 @@
+//! CVSS v3.1 combined Base/Temporal/Environmental vector
+
+use super::{Base, Environmental, Score, Temporal};
+use crate::{Error, MetricType, Result, PREFIX};
+use alloc::{
+    borrow::ToOwned,
+    string::{String, ToString},
+};
+use core::{fmt, str::FromStr};
+
+#[cfg(feature = "std")]
+use crate::Severity;
+
+/// A full CVSS v3.1 vector, composing the Base, Temporal and Environmental
+/// metric groups (mirroring the `base`/`temporal`/`environmental` structure
+/// used by other CVSS v3.1 implementations, e.g. the `cvssrust` crate) and
+/// exposing their combined score.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Vector {
+    /// Base Metric Group
+    pub base: Base,
+
+    /// Temporal Metric Group
+    pub temporal: Temporal,
+
+    /// Environmental Metric Group
+    pub environmental: Environmental,
+}
+
+impl Vector {
+    /// Calculate the overall CVSS v3.1 score: the Environmental score if any
+    /// Environmental metrics are set, otherwise the Temporal score if any
+    /// Temporal metrics are set, otherwise the Base score alone.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn score(&self) -> Score {
+        if self.environmental.has_metrics() {
+            self.environmental.score(&self.base)
+        } else if self.temporal.has_metrics() {
+            self.temporal.score(&self.base)
+        } else {
+            self.base.score()
+        }
+    }
+
+    /// Calculate the overall CVSS v3.1 `Severity`, using the same
+    /// Base/Temporal/Environmental precedence as [`Vector::score`].
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn severity(&self) -> Severity {
+        self.score().severity()
+    }
+}
+
+impl fmt::Display for Vector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // `Temporal`/`Environmental` each render their own `CVSS:3.<minor>` prefix; since a
+        // combined vector string carries only one prefix, skip past it in their output and
+        // append just the `/ID:VALUE` metrics that follow (if any).
+        let prefix_len = prefix(self.base.minor_version).len();
+
+        write!(f, "{}", self.base)?;
+        write!(f, "{}", &self.temporal.to_string()[prefix_len..])?;
+        write!(f, "{}", &self.environmental.to_string()[prefix_len..])?;
+
+        Ok(())
+    }
+}
+
+impl FromStr for Vector {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut components = s.split('/');
+
+        let prefix = components.next().ok_or_else(|| Error::InvalidPrefix {
+            prefix: s.to_owned(),
+        })?;
+
+        // Split the vector string back out into one sub-vector per metric group, each sharing
+        // the original prefix, and delegate to that group's own parser.
+        let mut base_vector = prefix.to_owned();
+        let mut temporal_vector = prefix.to_owned();
+        let mut environmental_vector = prefix.to_owned();
+
+        for component in components {
+            let id = component
+                .split(':')
+                .next()
+                .ok_or_else(|| Error::InvalidComponent {
+                    component: component.to_owned(),
+                })?;
+
+            let vector = match id.to_ascii_uppercase().parse::<MetricType>()? {
+                MetricType::E | MetricType::RL | MetricType::RC => &mut temporal_vector,
+                MetricType::CR
+                | MetricType::IR
+                | MetricType::AR
+                | MetricType::MAV
+                | MetricType::MAC
+                | MetricType::MPR
+                | MetricType::MUI
+                | MetricType::MS
+                | MetricType::MC
+                | MetricType::MI
+                | MetricType::MA => &mut environmental_vector,
+                _ => &mut base_vector,
+            };
+
+            vector.push('/');
+            vector.push_str(component);
+        }
+
+        Ok(Self {
+            base: base_vector.parse()?,
+            temporal: temporal_vector.parse()?,
+            environmental: environmental_vector.parse()?,
+        })
+    }
+}
+
+/// The shared `CVSS:3.<minor>` prefix rendered by `Base`/`Temporal`/`Environmental::fmt`.
+fn prefix(minor_version: usize) -> String {
+    alloc::format!("{}:3.{}", PREFIX, minor_version)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_VECTOR: &str =
+        "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/E:F/RL:O/RC:C/CR:H/IR:H/AR:H";
+
+    #[test]
+    fn parses_and_round_trips() {
+        let vector: Vector = EXAMPLE_VECTOR.parse().unwrap();
+        assert_eq!(vector.to_string(), EXAMPLE_VECTOR);
+    }
+
+    #[test]
+    fn base_only_round_trips_without_trailing_groups() {
+        let base_only = "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H";
+        let vector: Vector = base_only.parse().unwrap();
+        assert!(!vector.temporal.has_metrics());
+        assert!(!vector.environmental.has_metrics());
+        assert_eq!(vector.to_string(), base_only);
+    }
+}
@@ -2,10 +2,15 @@
 //!
 //! <https://www.first.org/cvss/specification-document>
 
-// TODO(tarcieri): Environmental and Temporal Metrics
-
 pub mod base;
+pub mod environmental;
+pub mod threat;
 
+mod macrovector;
 mod score;
+mod table;
+mod vector;
 
-pub use self::{base::Base, score::Score};
+pub use self::{
+    base::Base, environmental::Environmental, score::Score, threat::Threat, vector::Vector,
+};
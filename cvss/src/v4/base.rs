@@ -0,0 +1,327 @@
+//! CVSS v4.0 Base Metric Group
+
+pub mod ac;
+pub mod at;
+pub mod av;
+pub mod pr;
+pub mod sa;
+pub mod sc;
+pub mod si;
+pub mod ui;
+pub mod va;
+pub mod vc;
+pub mod vi;
+
+pub use self::{
+    ac::AttackComplexity, at::AttackRequirements, av::AttackVector, pr::PrivilegesRequired,
+    sa::AvailabilityImpactToSubsequentSystem, sc::ConfidentialityImpactToSubsequentSystem,
+    si::IntegrityImpactToSubsequentSystem, ui::UserInteraction,
+    va::AvailabilityImpactToVulnerableSystem, vc::ConfidentialityImpactToVulnerableSystem,
+    vi::IntegrityImpactToVulnerableSystem,
+};
+
+use super::{macrovector, Environmental, Score, Threat};
+use crate::{Error, Metric, MetricType, Result, PREFIX};
+use alloc::{
+    borrow::ToOwned,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{fmt, str::FromStr};
+
+#[cfg(feature = "serde")]
+use serde::{de, ser, Deserialize, Serialize};
+
+#[cfg(feature = "std")]
+use crate::Severity;
+
+/// CVSS v4.0 Base Metric Group
+///
+/// Described in CVSS v4.0 Specification: Section 2:
+/// <https://www.first.org/cvss/v4.0/specification-document>
+///
+/// > The Base metric group represents the intrinsic characteristics of a vulnerability that are
+/// > constant over time and across user environments.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Base {
+    /// Minor component of the version
+    pub minor_version: usize,
+
+    /// Attack Vector (AV)
+    pub av: AttackVector,
+
+    /// Attack Complexity (AC)
+    pub ac: AttackComplexity,
+
+    /// Attack Requirements (AT)
+    pub at: AttackRequirements,
+
+    /// Privileges Required (PR)
+    pub pr: PrivilegesRequired,
+
+    /// User Interaction (UI)
+    pub ui: UserInteraction,
+
+    /// Vulnerable System Confidentiality Impact (VC)
+    pub vc: ConfidentialityImpactToVulnerableSystem,
+
+    /// Vulnerable System Integrity Impact (VI)
+    pub vi: IntegrityImpactToVulnerableSystem,
+
+    /// Vulnerable System Availability Impact (VA)
+    pub va: AvailabilityImpactToVulnerableSystem,
+
+    /// Subsequent System Confidentiality Impact (SC)
+    pub sc: ConfidentialityImpactToSubsequentSystem,
+
+    /// Subsequent System Integrity Impact (SI)
+    pub si: IntegrityImpactToSubsequentSystem,
+
+    /// Subsequent System Availability Impact (SA)
+    pub sa: AvailabilityImpactToSubsequentSystem,
+}
+
+impl Base {
+    /// Calculate the CVSS v4.0 score using the MacroVector algorithm described
+    /// in CVSS v4.0 Specification: Section 8:
+    /// <https://www.first.org/cvss/v4.0/specification-document>
+    ///
+    /// > CVSS v4.0 is not a formula-based scoring system like its predecessors. Instead, scores
+    /// > are generated via a lookup table, which is populated using a technique known as "expert
+    /// > elicitation", keyed on a reduction of the full vector into a small number of
+    /// > equivalence classes, and refined using a distance metric within each equivalence
+    /// > class.
+    ///
+    /// Threat and Environmental metrics aren't available from the Base vector alone, so this
+    /// defaults both to Not Defined (i.e. the worst-case EQ5/EQ6 classification); use
+    /// [`super::Vector::score`] to factor in an actual Threat/Environmental context.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn score(&self) -> Score {
+        Score::new(macrovector::score(
+            self,
+            &Threat::default(),
+            &Environmental::default(),
+        ))
+        .roundup()
+    }
+
+    /// Calculate the CVSS v4.0 `Severity` according to the Qualitative
+    /// Severity Rating Scale (i.e. None / Low / Medium / High / Critical)
+    ///
+    /// Described in CVSS v4.0 Specification: Section 6:
+    /// <https://www.first.org/cvss/v4.0/specification-document>
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn severity(&self) -> Severity {
+        self.score().severity()
+    }
+
+    /// Decompose the Base score into its constituent parts, suitable for
+    /// storage in structured records (e.g. protobuf/JSON schemas) without
+    /// the consumer having to re-derive them from the parsed vector string.
+    ///
+    /// CVSS v4.0 has no formula-based Exploitability/Impact sub-scores (see
+    /// [`Base::score`]), so unlike the v2.0 Base and v3.1 Environmental
+    /// `Scores` types this one carries only the overall score.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn to_scores(&self) -> Scores {
+        Scores {
+            vector: self.to_string(),
+            base_score: self.score().value(),
+            severity: self.severity(),
+        }
+    }
+}
+
+/// Decomposed CVSS v4.0 Base scores.
+///
+/// Returned by [`Base::to_scores`].
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Scores {
+    /// Full CVSS v4.0 vector string
+    pub vector: String,
+
+    /// Overall Base score
+    pub base_score: f64,
+
+    /// Qualitative Severity Rating
+    pub severity: Severity,
+}
+
+macro_rules! write_metrics {
+    ($f:expr, $($metric:expr),+) => {
+        $(
+            write!($f, "/{}", $metric)?;
+        )+
+    };
+}
+
+impl fmt::Display for Base {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:4.{}", PREFIX, self.minor_version)?;
+        write_metrics!(
+            f, self.av, self.ac, self.at, self.pr, self.ui, self.vc, self.vi, self.va, self.sc,
+            self.si, self.sa
+        );
+        Ok(())
+    }
+}
+
+impl FromStr for Base {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let component_vec = s
+            .split('/')
+            .map(|component| {
+                let mut parts = component.split(':');
+
+                let id = parts.next().ok_or_else(|| Error::InvalidComponent {
+                    component: component.to_owned(),
+                })?;
+
+                let value = parts.next().ok_or_else(|| Error::InvalidComponent {
+                    component: component.to_owned(),
+                })?;
+
+                if parts.next().is_some() {
+                    return Err(Error::InvalidComponent {
+                        component: component.to_owned(),
+                    });
+                }
+
+                Ok((id, value))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut components = component_vec.iter();
+        let &(id, version_string) = components.next().ok_or(Error::InvalidPrefix {
+            prefix: s.to_owned(),
+        })?;
+
+        if id != PREFIX {
+            return Err(Error::InvalidPrefix {
+                prefix: id.to_owned(),
+            });
+        }
+
+        let minor_version = match version_string {
+            "4.0" => 0,
+            _ => {
+                return Err(Error::UnsupportedVersion {
+                    version: version_string.to_owned(),
+                })
+            }
+        };
+
+        let mut av = None;
+        let mut ac = None;
+        let mut at = None;
+        let mut pr = None;
+        let mut ui = None;
+        let mut vc = None;
+        let mut vi = None;
+        let mut va = None;
+        let mut sc = None;
+        let mut si = None;
+        let mut sa = None;
+
+        for &component in components {
+            let id = component.0.to_ascii_uppercase();
+            let value = component.1.to_ascii_uppercase();
+
+            match id.parse::<MetricType>()? {
+                MetricType::AV => av = Some(value.parse()?),
+                MetricType::AC => ac = Some(value.parse()?),
+                MetricType::AT => at = Some(value.parse()?),
+                MetricType::PR => pr = Some(value.parse()?),
+                MetricType::UI => ui = Some(value.parse()?),
+                MetricType::VC => vc = Some(value.parse()?),
+                MetricType::VI => vi = Some(value.parse()?),
+                MetricType::VA => va = Some(value.parse()?),
+                MetricType::SC => sc = Some(value.parse()?),
+                MetricType::SI => si = Some(value.parse()?),
+                MetricType::SA => sa = Some(value.parse()?),
+                other => {
+                    return Err(Error::UnknownMetric {
+                        name: other.to_string(),
+                    })
+                }
+            }
+        }
+
+        Ok(Self {
+            minor_version,
+            av: required(av, "AV")?,
+            ac: required(ac, "AC")?,
+            at: required(at, "AT")?,
+            pr: required(pr, "PR")?,
+            ui: required(ui, "UI")?,
+            vc: required(vc, "VC")?,
+            vi: required(vi, "VI")?,
+            va: required(va, "VA")?,
+            sc: required(sc, "SC")?,
+            si: required(si, "SI")?,
+            sa: required(sa, "SA")?,
+        })
+    }
+}
+
+/// Unlike the Threat/Environmental metric groups, CVSS v4.0 Base metrics have no "Not
+/// Defined" default: every one of them is mandatory, so a vector string missing one is invalid
+/// rather than implicitly filled in.
+fn required<T>(value: Option<T>, id: &str) -> Result<T> {
+    value.ok_or_else(|| Error::InvalidComponent {
+        component: id.to_owned(),
+    })
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Base {
+    fn deserialize<D: de::Deserializer<'de>>(
+        deserializer: D,
+    ) -> core::result::Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl Serialize for Base {
+    fn serialize<S: ser::Serializer>(
+        &self,
+        serializer: S,
+    ) -> core::result::Result<S::Ok, S::Error> {
+        self.to_string().serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_VECTOR: &str =
+        "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:H/SI:H/SA:H";
+
+    #[test]
+    fn parses_and_round_trips() {
+        let base: Base = EXAMPLE_VECTOR.parse().unwrap();
+        assert_eq!(base.to_string(), EXAMPLE_VECTOR);
+    }
+
+    // Every Base metric is mandatory in CVSS v4.0: a vector missing one must error rather
+    // than silently defaulting it.
+    #[test]
+    fn errors_on_missing_mandatory_metric() {
+        let result = "CVSS:4.0/AV:N/AC:L".parse::<Base>();
+        assert!(result.is_err());
+    }
+}
@@ -59,7 +59,8 @@ impl Default for AttackComplexity {
 impl Metric for AttackComplexity {
     const TYPE: MetricType = MetricType::AC;
 
-    // FIXME
+    /// Not used directly: CVSS v4.0 scores via the MacroVector algorithm in
+    /// [`crate::v4::Base::score`] rather than per-metric weights.
     fn score(self) -> f64 {
         match self {
             AttackComplexity::High => 0.44,
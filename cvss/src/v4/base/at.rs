@@ -0,0 +1,77 @@
+//! Attack Requirements (AT)
+
+use crate::{Error, Metric, MetricType};
+use alloc::borrow::ToOwned;
+use core::{fmt, str::FromStr};
+
+/// Attack Requirements (AT) - CVSS v4.0 Base Metric Group
+///
+/// Described in CVSS v4.0 Specification: Section 2.1.3:
+/// <https://www.first.org/cvss/v4.0/specification-document>
+///
+/// > This metric captures the prerequisite deployment and execution conditions or variables of
+/// > the vulnerable system that enable the attack. These differ from security-enhancing
+/// > techniques/technologies (ref Attack Complexity) as the primary purpose of these conditions is
+/// > not to explicitly mitigate attacks, but rather, stem from environment/configuration
+/// > effects that are out of control of the attacker.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum AttackRequirements {
+    /// None (N)
+    ///
+    /// > The successful attack does not depend on the deployment and execution conditions of the
+    /// > vulnerable system. There are no additional execution conditions for this vulnerability.
+    None,
+
+    /// Present (P)
+    ///
+    /// > The successful attack depends on the presence of specific deployment and execution
+    /// > conditions of the vulnerable system that enable the attack.
+    Present,
+}
+
+impl Default for AttackRequirements {
+    fn default() -> AttackRequirements {
+        AttackRequirements::None
+    }
+}
+
+impl Metric for AttackRequirements {
+    const TYPE: MetricType = MetricType::AT;
+
+    /// Not used directly: CVSS v4.0 scores via the MacroVector algorithm in
+    /// [`crate::v4::Base::score`] rather than per-metric weights.
+    fn score(self) -> f64 {
+        match self {
+            AttackRequirements::None => 0.0,
+            AttackRequirements::Present => 1.0,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            AttackRequirements::None => "N",
+            AttackRequirements::Present => "P",
+        }
+    }
+}
+
+impl fmt::Display for AttackRequirements {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::name(), self.as_str())
+    }
+}
+
+impl FromStr for AttackRequirements {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "N" => Ok(AttackRequirements::None),
+            "P" => Ok(AttackRequirements::Present),
+            _ => Err(Error::InvalidMetric {
+                metric_type: Self::TYPE,
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
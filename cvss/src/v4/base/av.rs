@@ -66,10 +66,17 @@ pub enum AttackVector {
     Network,
 }
 
+impl Default for AttackVector {
+    fn default() -> AttackVector {
+        AttackVector::Network
+    }
+}
+
 impl Metric for AttackVector {
     const TYPE: MetricType = MetricType::AV;
 
-    // FIXME: replace
+    /// Not used directly: CVSS v4.0 scores via the MacroVector algorithm in
+    /// [`crate::v4::Base::score`] rather than per-metric weights.
     fn score(self) -> f64 {
         match self {
             AttackVector::Physical => 0.20,
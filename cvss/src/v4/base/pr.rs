@@ -0,0 +1,83 @@
+//! Privileges Required (PR)
+
+use crate::{Error, Metric, MetricType};
+use alloc::borrow::ToOwned;
+use core::{fmt, str::FromStr};
+
+/// Privileges Required (PR) - CVSS v4.0 Base Metric Group
+///
+/// Described in CVSS v4.0 Specification: Section 2.1.4:
+/// <https://www.first.org/cvss/v4.0/specification-document>
+///
+/// > This metric describes the level of privileges an attacker must possess prior to
+/// > successfully exploiting the vulnerability.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum PrivilegesRequired {
+    /// High (H)
+    ///
+    /// > The attacker requires privileges that provide significant (e.g., administrative) control
+    /// > over the vulnerable system allowing full access to the vulnerable system's settings and files.
+    High,
+
+    /// Low (L)
+    ///
+    /// > The attacker requires privileges that provide basic user capabilities that could normally
+    /// > affect only settings and files owned by a user.
+    Low,
+
+    /// None (N)
+    ///
+    /// > The attacker is unauthorized prior to attack, and therefore does not require any access
+    /// > to settings or files of the vulnerable system to carry out an attack.
+    None,
+}
+
+impl Default for PrivilegesRequired {
+    fn default() -> PrivilegesRequired {
+        PrivilegesRequired::None
+    }
+}
+
+impl Metric for PrivilegesRequired {
+    const TYPE: MetricType = MetricType::PR;
+
+    /// Not used directly: CVSS v4.0 scores via the MacroVector algorithm in
+    /// [`crate::v4::Base::score`] rather than per-metric weights.
+    fn score(self) -> f64 {
+        match self {
+            PrivilegesRequired::High => 0.2,
+            PrivilegesRequired::Low => 0.5,
+            PrivilegesRequired::None => 0.85,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            PrivilegesRequired::High => "H",
+            PrivilegesRequired::Low => "L",
+            PrivilegesRequired::None => "N",
+        }
+    }
+}
+
+impl fmt::Display for PrivilegesRequired {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::name(), self.as_str())
+    }
+}
+
+impl FromStr for PrivilegesRequired {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "H" => Ok(PrivilegesRequired::High),
+            "L" => Ok(PrivilegesRequired::Low),
+            "N" => Ok(PrivilegesRequired::None),
+            _ => Err(Error::InvalidMetric {
+                metric_type: Self::TYPE,
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
@@ -0,0 +1,82 @@
+//! Subsequent System Availability Impact (SA)
+
+use crate::{Error, Metric, MetricType};
+use alloc::borrow::ToOwned;
+use core::{fmt, str::FromStr};
+
+/// Subsequent System Availability Impact (SA) - CVSS v4.0 Base Metric Group
+///
+/// Described in CVSS v4.0 Specification: Section 2.2.3:
+/// <https://www.first.org/cvss/v4.0/specification-document>
+///
+/// > This metric measures the impact to the availability of a *subsequent system* resulting
+/// > from a successfully exploited vulnerability.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum AvailabilityImpactToSubsequentSystem {
+    /// High (H)
+    ///
+    /// > There is a total loss of availability, resulting in the attacker being able to fully
+    /// > deny access to resources in the subsequent system.
+    High,
+
+    /// Low (L)
+    ///
+    /// > There is reduced performance or interruptions in resource availability.
+    Low,
+
+    /// Negligible (N)
+    ///
+    /// > There is no impact to availability within the subsequent system or all availability
+    /// > impact is constrained to the vulnerable system.
+    Negligible,
+}
+
+impl Default for AvailabilityImpactToSubsequentSystem {
+    fn default() -> AvailabilityImpactToSubsequentSystem {
+        AvailabilityImpactToSubsequentSystem::Negligible
+    }
+}
+
+impl Metric for AvailabilityImpactToSubsequentSystem {
+    const TYPE: MetricType = MetricType::SA;
+
+    /// Not used directly: CVSS v4.0 scores via the MacroVector algorithm in
+    /// [`crate::v4::Base::score`] rather than per-metric weights.
+    fn score(self) -> f64 {
+        match self {
+            AvailabilityImpactToSubsequentSystem::High => 1.0,
+            AvailabilityImpactToSubsequentSystem::Low => 0.5,
+            AvailabilityImpactToSubsequentSystem::Negligible => 0.0,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            AvailabilityImpactToSubsequentSystem::High => "H",
+            AvailabilityImpactToSubsequentSystem::Low => "L",
+            AvailabilityImpactToSubsequentSystem::Negligible => "N",
+        }
+    }
+}
+
+impl fmt::Display for AvailabilityImpactToSubsequentSystem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::name(), self.as_str())
+    }
+}
+
+impl FromStr for AvailabilityImpactToSubsequentSystem {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "H" => Ok(AvailabilityImpactToSubsequentSystem::High),
+            "L" => Ok(AvailabilityImpactToSubsequentSystem::Low),
+            "N" => Ok(AvailabilityImpactToSubsequentSystem::Negligible),
+            _ => Err(Error::InvalidMetric {
+                metric_type: Self::TYPE,
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
@@ -0,0 +1,83 @@
+//! Subsequent System Confidentiality Impact (SC)
+
+use crate::{Error, Metric, MetricType};
+use alloc::borrow::ToOwned;
+use core::{fmt, str::FromStr};
+
+/// Subsequent System Confidentiality Impact (SC) - CVSS v4.0 Base Metric Group
+///
+/// Described in CVSS v4.0 Specification: Section 2.2.1:
+/// <https://www.first.org/cvss/v4.0/specification-document>
+///
+/// > This metric measures the impact to the confidentiality of the information managed by a
+/// > *subsequent system* due to a successfully exploited vulnerability.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum ConfidentialityImpactToSubsequentSystem {
+    /// High (H)
+    ///
+    /// > There is a total loss of confidentiality, resulting in all resources within the
+    /// > subsequent system being divulged to the attacker.
+    High,
+
+    /// Low (L)
+    ///
+    /// > There is some loss of confidentiality. Access to some restricted information is
+    /// > obtained, but the attacker does not have control over what information is obtained.
+    Low,
+
+    /// Negligible (N)
+    ///
+    /// > There is no loss of confidentiality within the subsequent system or all confidentiality
+    /// > impact is constrained to the vulnerable system.
+    Negligible,
+}
+
+impl Default for ConfidentialityImpactToSubsequentSystem {
+    fn default() -> ConfidentialityImpactToSubsequentSystem {
+        ConfidentialityImpactToSubsequentSystem::Negligible
+    }
+}
+
+impl Metric for ConfidentialityImpactToSubsequentSystem {
+    const TYPE: MetricType = MetricType::SC;
+
+    /// Not used directly: CVSS v4.0 scores via the MacroVector algorithm in
+    /// [`crate::v4::Base::score`] rather than per-metric weights.
+    fn score(self) -> f64 {
+        match self {
+            ConfidentialityImpactToSubsequentSystem::High => 1.0,
+            ConfidentialityImpactToSubsequentSystem::Low => 0.5,
+            ConfidentialityImpactToSubsequentSystem::Negligible => 0.0,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            ConfidentialityImpactToSubsequentSystem::High => "H",
+            ConfidentialityImpactToSubsequentSystem::Low => "L",
+            ConfidentialityImpactToSubsequentSystem::Negligible => "N",
+        }
+    }
+}
+
+impl fmt::Display for ConfidentialityImpactToSubsequentSystem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::name(), self.as_str())
+    }
+}
+
+impl FromStr for ConfidentialityImpactToSubsequentSystem {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "H" => Ok(ConfidentialityImpactToSubsequentSystem::High),
+            "L" => Ok(ConfidentialityImpactToSubsequentSystem::Low),
+            "N" => Ok(ConfidentialityImpactToSubsequentSystem::Negligible),
+            _ => Err(Error::InvalidMetric {
+                metric_type: Self::TYPE,
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
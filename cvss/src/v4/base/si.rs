@@ -0,0 +1,83 @@
+//! Subsequent System Integrity Impact (SI)
+
+use crate::{Error, Metric, MetricType};
+use alloc::borrow::ToOwned;
+use core::{fmt, str::FromStr};
+
+/// Subsequent System Integrity Impact (SI) - CVSS v4.0 Base Metric Group
+///
+/// Described in CVSS v4.0 Specification: Section 2.2.2:
+/// <https://www.first.org/cvss/v4.0/specification-document>
+///
+/// > This metric measures the impact to integrity of a successfully exploited vulnerability on
+/// > a *subsequent system*.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum IntegrityImpactToSubsequentSystem {
+    /// High (H)
+    ///
+    /// > There is a total loss of integrity, or a complete loss of protection, within the
+    /// > subsequent system.
+    High,
+
+    /// Low (L)
+    ///
+    /// > Modification of data is possible, but the attacker does not have control over the
+    /// > consequence of a modification, or the amount of modification is limited.
+    Low,
+
+    /// Negligible (N)
+    ///
+    /// > There is no loss of integrity within the subsequent system or all integrity impact is
+    /// > constrained to the vulnerable system.
+    Negligible,
+}
+
+impl Default for IntegrityImpactToSubsequentSystem {
+    fn default() -> IntegrityImpactToSubsequentSystem {
+        IntegrityImpactToSubsequentSystem::Negligible
+    }
+}
+
+impl Metric for IntegrityImpactToSubsequentSystem {
+    const TYPE: MetricType = MetricType::SI;
+
+    /// Not used directly: CVSS v4.0 scores via the MacroVector algorithm in
+    /// [`crate::v4::Base::score`] rather than per-metric weights.
+    fn score(self) -> f64 {
+        match self {
+            IntegrityImpactToSubsequentSystem::High => 1.0,
+            IntegrityImpactToSubsequentSystem::Low => 0.5,
+            IntegrityImpactToSubsequentSystem::Negligible => 0.0,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            IntegrityImpactToSubsequentSystem::High => "H",
+            IntegrityImpactToSubsequentSystem::Low => "L",
+            IntegrityImpactToSubsequentSystem::Negligible => "N",
+        }
+    }
+}
+
+impl fmt::Display for IntegrityImpactToSubsequentSystem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::name(), self.as_str())
+    }
+}
+
+impl FromStr for IntegrityImpactToSubsequentSystem {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "H" => Ok(IntegrityImpactToSubsequentSystem::High),
+            "L" => Ok(IntegrityImpactToSubsequentSystem::Low),
+            "N" => Ok(IntegrityImpactToSubsequentSystem::Negligible),
+            _ => Err(Error::InvalidMetric {
+                metric_type: Self::TYPE,
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
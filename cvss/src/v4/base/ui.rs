@@ -0,0 +1,83 @@
+//! User Interaction (UI)
+
+use crate::{Error, Metric, MetricType};
+use alloc::borrow::ToOwned;
+use core::{fmt, str::FromStr};
+
+/// User Interaction (UI) - CVSS v4.0 Base Metric Group
+///
+/// Described in CVSS v4.0 Specification: Section 2.1.5:
+/// <https://www.first.org/cvss/v4.0/specification-document>
+///
+/// > This metric captures the requirement for a human user, other than the attacker, to
+/// > participate in the successful compromise of the vulnerable system.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum UserInteraction {
+    /// Active (A)
+    ///
+    /// > Successful exploitation of this vulnerability requires a targeted user to perform
+    /// > specific, conscious interactions with the vulnerable system and the attacker's payload,
+    /// > or the target's actions would need to actively circumvent security mechanisms.
+    Active,
+
+    /// Passive (P)
+    ///
+    /// > Successful exploitation of this vulnerability requires limited interaction by the
+    /// > targeted user with the vulnerable system and the attacker's payload.
+    Passive,
+
+    /// None (N)
+    ///
+    /// > The vulnerable system can be exploited without interaction from any user.
+    None,
+}
+
+impl Default for UserInteraction {
+    fn default() -> UserInteraction {
+        UserInteraction::None
+    }
+}
+
+impl Metric for UserInteraction {
+    const TYPE: MetricType = MetricType::UI;
+
+    /// Not used directly: CVSS v4.0 scores via the MacroVector algorithm in
+    /// [`crate::v4::Base::score`] rather than per-metric weights.
+    fn score(self) -> f64 {
+        match self {
+            UserInteraction::Active => 0.2,
+            UserInteraction::Passive => 0.5,
+            UserInteraction::None => 0.85,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            UserInteraction::Active => "A",
+            UserInteraction::Passive => "P",
+            UserInteraction::None => "N",
+        }
+    }
+}
+
+impl fmt::Display for UserInteraction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::name(), self.as_str())
+    }
+}
+
+impl FromStr for UserInteraction {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "A" => Ok(UserInteraction::Active),
+            "P" => Ok(UserInteraction::Passive),
+            "N" => Ok(UserInteraction::None),
+            _ => Err(Error::InvalidMetric {
+                metric_type: Self::TYPE,
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
@@ -0,0 +1,81 @@
+//! Vulnerable System Availability Impact (VA)
+
+use crate::{Error, Metric, MetricType};
+use alloc::borrow::ToOwned;
+use core::{fmt, str::FromStr};
+
+/// Vulnerable System Availability Impact (VA) - CVSS v4.0 Base Metric Group
+///
+/// Described in CVSS v4.0 Specification: Section 2.2.3:
+/// <https://www.first.org/cvss/v4.0/specification-document>
+///
+/// > This metric measures the impact to the availability of the *vulnerable system* resulting
+/// > from a successfully exploited vulnerability.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum AvailabilityImpactToVulnerableSystem {
+    /// High (H)
+    ///
+    /// > There is a total loss of availability, resulting in the attacker being able to fully
+    /// > deny access to resources in the vulnerable system.
+    High,
+
+    /// Low (L)
+    ///
+    /// > There is reduced performance or interruptions in resource availability.
+    Low,
+
+    /// None (N)
+    ///
+    /// > There is no impact to availability within the vulnerable system.
+    None,
+}
+
+impl Default for AvailabilityImpactToVulnerableSystem {
+    fn default() -> AvailabilityImpactToVulnerableSystem {
+        AvailabilityImpactToVulnerableSystem::None
+    }
+}
+
+impl Metric for AvailabilityImpactToVulnerableSystem {
+    const TYPE: MetricType = MetricType::VA;
+
+    /// Not used directly: CVSS v4.0 scores via the MacroVector algorithm in
+    /// [`crate::v4::Base::score`] rather than per-metric weights.
+    fn score(self) -> f64 {
+        match self {
+            AvailabilityImpactToVulnerableSystem::High => 1.0,
+            AvailabilityImpactToVulnerableSystem::Low => 0.5,
+            AvailabilityImpactToVulnerableSystem::None => 0.0,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            AvailabilityImpactToVulnerableSystem::High => "H",
+            AvailabilityImpactToVulnerableSystem::Low => "L",
+            AvailabilityImpactToVulnerableSystem::None => "N",
+        }
+    }
+}
+
+impl fmt::Display for AvailabilityImpactToVulnerableSystem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::name(), self.as_str())
+    }
+}
+
+impl FromStr for AvailabilityImpactToVulnerableSystem {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "H" => Ok(AvailabilityImpactToVulnerableSystem::High),
+            "L" => Ok(AvailabilityImpactToVulnerableSystem::Low),
+            "N" => Ok(AvailabilityImpactToVulnerableSystem::None),
+            _ => Err(Error::InvalidMetric {
+                metric_type: Self::TYPE,
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
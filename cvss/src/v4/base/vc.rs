@@ -0,0 +1,82 @@
+//! Vulnerable System Confidentiality Impact (VC)
+
+use crate::{Error, Metric, MetricType};
+use alloc::borrow::ToOwned;
+use core::{fmt, str::FromStr};
+
+/// Vulnerable System Confidentiality Impact (VC) - CVSS v4.0 Base Metric Group
+///
+/// Described in CVSS v4.0 Specification: Section 2.2.1:
+/// <https://www.first.org/cvss/v4.0/specification-document>
+///
+/// > This metric measures the impact to the confidentiality of the information managed by the
+/// > *vulnerable system* due to a successfully exploited vulnerability.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum ConfidentialityImpactToVulnerableSystem {
+    /// High (H)
+    ///
+    /// > There is a total loss of confidentiality, resulting in all information within the
+    /// > vulnerable system being divulged to the attacker.
+    High,
+
+    /// Low (L)
+    ///
+    /// > There is some loss of confidentiality. Access to some restricted information is
+    /// > obtained, but the attacker does not have control over what information is obtained.
+    Low,
+
+    /// None (N)
+    ///
+    /// > There is no loss of confidentiality within the vulnerable system.
+    None,
+}
+
+impl Default for ConfidentialityImpactToVulnerableSystem {
+    fn default() -> ConfidentialityImpactToVulnerableSystem {
+        ConfidentialityImpactToVulnerableSystem::None
+    }
+}
+
+impl Metric for ConfidentialityImpactToVulnerableSystem {
+    const TYPE: MetricType = MetricType::VC;
+
+    /// Not used directly: CVSS v4.0 scores via the MacroVector algorithm in
+    /// [`crate::v4::Base::score`] rather than per-metric weights.
+    fn score(self) -> f64 {
+        match self {
+            ConfidentialityImpactToVulnerableSystem::High => 1.0,
+            ConfidentialityImpactToVulnerableSystem::Low => 0.5,
+            ConfidentialityImpactToVulnerableSystem::None => 0.0,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            ConfidentialityImpactToVulnerableSystem::High => "H",
+            ConfidentialityImpactToVulnerableSystem::Low => "L",
+            ConfidentialityImpactToVulnerableSystem::None => "N",
+        }
+    }
+}
+
+impl fmt::Display for ConfidentialityImpactToVulnerableSystem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::name(), self.as_str())
+    }
+}
+
+impl FromStr for ConfidentialityImpactToVulnerableSystem {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "H" => Ok(ConfidentialityImpactToVulnerableSystem::High),
+            "L" => Ok(ConfidentialityImpactToVulnerableSystem::Low),
+            "N" => Ok(ConfidentialityImpactToVulnerableSystem::None),
+            _ => Err(Error::InvalidMetric {
+                metric_type: Self::TYPE,
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
@@ -0,0 +1,81 @@
+//! Vulnerable System Integrity Impact (VI)
+
+use crate::{Error, Metric, MetricType};
+use alloc::borrow::ToOwned;
+use core::{fmt, str::FromStr};
+
+/// Vulnerable System Integrity Impact (VI) - CVSS v4.0 Base Metric Group
+///
+/// Described in CVSS v4.0 Specification: Section 2.2.2:
+/// <https://www.first.org/cvss/v4.0/specification-document>
+///
+/// > This metric measures the impact to integrity of a successfully exploited vulnerability on
+/// > the *vulnerable system*.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum IntegrityImpactToVulnerableSystem {
+    /// High (H)
+    ///
+    /// > There is a total loss of integrity, or a complete loss of protection.
+    High,
+
+    /// Low (L)
+    ///
+    /// > Modification of data is possible, but the attacker does not have control over the
+    /// > consequence of a modification, or the amount of modification is limited.
+    Low,
+
+    /// None (N)
+    ///
+    /// > There is no loss of integrity within the vulnerable system.
+    None,
+}
+
+impl Default for IntegrityImpactToVulnerableSystem {
+    fn default() -> IntegrityImpactToVulnerableSystem {
+        IntegrityImpactToVulnerableSystem::None
+    }
+}
+
+impl Metric for IntegrityImpactToVulnerableSystem {
+    const TYPE: MetricType = MetricType::VI;
+
+    /// Not used directly: CVSS v4.0 scores via the MacroVector algorithm in
+    /// [`crate::v4::Base::score`] rather than per-metric weights.
+    fn score(self) -> f64 {
+        match self {
+            IntegrityImpactToVulnerableSystem::High => 1.0,
+            IntegrityImpactToVulnerableSystem::Low => 0.5,
+            IntegrityImpactToVulnerableSystem::None => 0.0,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            IntegrityImpactToVulnerableSystem::High => "H",
+            IntegrityImpactToVulnerableSystem::Low => "L",
+            IntegrityImpactToVulnerableSystem::None => "N",
+        }
+    }
+}
+
+impl fmt::Display for IntegrityImpactToVulnerableSystem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::name(), self.as_str())
+    }
+}
+
+impl FromStr for IntegrityImpactToVulnerableSystem {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "H" => Ok(IntegrityImpactToVulnerableSystem::High),
+            "L" => Ok(IntegrityImpactToVulnerableSystem::Low),
+            "N" => Ok(IntegrityImpactToVulnerableSystem::None),
+            _ => Err(Error::InvalidMetric {
+                metric_type: Self::TYPE,
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
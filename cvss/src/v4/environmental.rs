@@ -0,0 +1,195 @@
+//! CVSS v4.0 Environmental Metric Group
+//!
+//! Only the Security Requirements (CR/IR/AR) are modeled here; the Modified Base metrics
+//! (MAV, MAC, MAT, MPR, MUI, MVC, MVI, MVA, MSC, MSI, MSA) described in CVSS v4.0 Specification:
+//! Section 4.2 aren't yet exposed.
+
+mod ar;
+mod cr;
+mod ir;
+
+pub use self::{ar::AvailabilityRequirement, cr::ConfidentialityRequirement, ir::IntegrityRequirement};
+
+use crate::{Error, Metric, MetricType, Result, PREFIX};
+use alloc::{borrow::ToOwned, string::ToString, vec::Vec};
+use core::{fmt, str::FromStr};
+
+#[cfg(feature = "serde")]
+use {
+    alloc::string::String,
+    serde::{de, ser, Deserialize, Serialize},
+};
+
+/// CVSS v4.0 Environmental Metric Group
+///
+/// Described in CVSS v4.0 Specification: Section 4:
+/// <https://www.first.org/cvss/v4.0/specification-document>
+///
+/// > These metrics enable the consumer analyst to customize the CVSS score depending on the
+/// > importance of the affected IT asset to the analyst's organization, measured in terms of
+/// > Confidentiality, Integrity, and Availability.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Environmental {
+    /// Minor component of the version
+    pub minor_version: usize,
+
+    /// Confidentiality Requirement (CR)
+    pub cr: Option<ConfidentialityRequirement>,
+
+    /// Integrity Requirement (IR)
+    pub ir: Option<IntegrityRequirement>,
+
+    /// Availability Requirement (AR)
+    pub ar: Option<AvailabilityRequirement>,
+}
+
+impl Environmental {
+    /// Are any Environmental metrics set?
+    pub fn has_metrics(&self) -> bool {
+        self.cr.is_some() || self.ir.is_some() || self.ar.is_some()
+    }
+
+    /// Is the Confidentiality Requirement High, or Not Defined (which behaves as High)?
+    pub(super) fn cr_is_high(&self) -> bool {
+        self.cr.unwrap_or_default().is_high()
+    }
+
+    /// Is the Integrity Requirement High, or Not Defined (which behaves as High)?
+    pub(super) fn ir_is_high(&self) -> bool {
+        self.ir.unwrap_or_default().is_high()
+    }
+
+    /// Is the Availability Requirement High, or Not Defined (which behaves as High)?
+    pub(super) fn ar_is_high(&self) -> bool {
+        self.ar.unwrap_or_default().is_high()
+    }
+}
+
+impl fmt::Display for Environmental {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:4.{}", PREFIX, self.minor_version)?;
+        if let Some(cr) = self.cr {
+            write!(f, "/{}", cr)?;
+        }
+        if let Some(ir) = self.ir {
+            write!(f, "/{}", ir)?;
+        }
+        if let Some(ar) = self.ar {
+            write!(f, "/{}", ar)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Environmental {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let component_vec = s
+            .split('/')
+            .map(|component| {
+                let mut parts = component.split(':');
+
+                let id = parts.next().ok_or_else(|| Error::InvalidComponent {
+                    component: component.to_owned(),
+                })?;
+
+                let value = parts.next().ok_or_else(|| Error::InvalidComponent {
+                    component: component.to_owned(),
+                })?;
+
+                if parts.next().is_some() {
+                    return Err(Error::InvalidComponent {
+                        component: component.to_owned(),
+                    });
+                }
+
+                Ok((id, value))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut components = component_vec.iter();
+        let &(id, version_string) = components.next().ok_or(Error::InvalidPrefix {
+            prefix: s.to_owned(),
+        })?;
+
+        if id != PREFIX {
+            return Err(Error::InvalidPrefix {
+                prefix: id.to_owned(),
+            });
+        }
+
+        let mut metrics = Self {
+            minor_version: match version_string {
+                "4.0" => 0,
+                _ => {
+                    return Err(Error::UnsupportedVersion {
+                        version: version_string.to_owned(),
+                    })
+                }
+            },
+            ..Default::default()
+        };
+
+        for &component in components {
+            let id = component.0.to_ascii_uppercase();
+            let value = component.1.to_ascii_uppercase();
+
+            match id.parse::<MetricType>()? {
+                MetricType::CR => metrics.cr = Some(value.parse()?),
+                MetricType::IR => metrics.ir = Some(value.parse()?),
+                MetricType::AR => metrics.ar = Some(value.parse()?),
+                other => {
+                    return Err(Error::UnknownMetric {
+                        name: other.to_string(),
+                    })
+                }
+            }
+        }
+
+        Ok(metrics)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Environmental {
+    fn deserialize<D: de::Deserializer<'de>>(
+        deserializer: D,
+    ) -> core::result::Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl Serialize for Environmental {
+    fn serialize<S: ser::Serializer>(
+        &self,
+        serializer: S,
+    ) -> core::result::Result<S::Ok, S::Error> {
+        self.to_string().serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_VECTOR: &str = "CVSS:4.0/CR:H/IR:M/AR:L";
+
+    #[test]
+    fn parses_and_round_trips() {
+        let environmental: Environmental = EXAMPLE_VECTOR.parse().unwrap();
+        assert_eq!(environmental.to_string(), EXAMPLE_VECTOR);
+    }
+
+    #[test]
+    fn no_metrics_round_trips_to_bare_prefix() {
+        let environmental: Environmental = "CVSS:4.0".parse().unwrap();
+        assert!(!environmental.has_metrics());
+        assert_eq!(environmental.to_string(), "CVSS:4.0");
+    }
+}
@@ -0,0 +1,103 @@
+//! Confidentiality Requirement (CR)
+
+use crate::{Error, Metric, MetricType, Result};
+use alloc::borrow::ToOwned;
+use core::{fmt, str::FromStr};
+
+/// Confidentiality Requirement (CR) - CVSS v4.0 Environmental Metric Group
+///
+/// Described in CVSS v4.0 Specification: Section 4.1:
+/// <https://www.first.org/cvss/v4.0/specification-document>
+///
+/// > These metrics enable the consumer analyst to customize the CVSS score depending on the
+/// > importance of the affected IT asset to the analyst's organization, measured in terms of
+/// > Confidentiality.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum ConfidentialityRequirement {
+    /// Not Defined (X)
+    ///
+    /// > The value assigned to the corresponding Base metric is used, i.e., Security
+    /// > Requirements are not considered and this has the same effect on scoring as assigning
+    /// > High.
+    NotDefined,
+
+    /// High (H)
+    ///
+    /// > Loss of Confidentiality is likely to have a catastrophic adverse effect on the
+    /// > organization or individuals associated with the organization (e.g., employees,
+    /// > customers).
+    High,
+
+    /// Medium (M)
+    ///
+    /// > Loss of Confidentiality is likely to have a serious adverse effect on the organization
+    /// > or individuals associated with the organization (e.g., employees, customers).
+    Medium,
+
+    /// Low (L)
+    ///
+    /// > Loss of Confidentiality is likely to have only a limited adverse effect on the
+    /// > organization or individuals associated with the organization (e.g., employees,
+    /// > customers).
+    Low,
+}
+
+impl ConfidentialityRequirement {
+    /// Is this requirement High, or Not Defined (which behaves as High)?
+    pub(crate) fn is_high(self) -> bool {
+        matches!(self, Self::High | Self::NotDefined)
+    }
+}
+
+impl Default for ConfidentialityRequirement {
+    fn default() -> ConfidentialityRequirement {
+        ConfidentialityRequirement::NotDefined
+    }
+}
+
+impl Metric for ConfidentialityRequirement {
+    const TYPE: MetricType = MetricType::CR;
+
+    /// Not used directly: CVSS v4.0 scores via the MacroVector algorithm in
+    /// [`crate::v4::macrovector`] rather than per-metric weights.
+    fn score(self) -> f64 {
+        match self {
+            ConfidentialityRequirement::NotDefined => 1.5,
+            ConfidentialityRequirement::High => 1.5,
+            ConfidentialityRequirement::Medium => 1.0,
+            ConfidentialityRequirement::Low => 0.5,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            ConfidentialityRequirement::NotDefined => "X",
+            ConfidentialityRequirement::High => "H",
+            ConfidentialityRequirement::Medium => "M",
+            ConfidentialityRequirement::Low => "L",
+        }
+    }
+}
+
+impl fmt::Display for ConfidentialityRequirement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::name(), self.as_str())
+    }
+}
+
+impl FromStr for ConfidentialityRequirement {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "X" => Ok(ConfidentialityRequirement::NotDefined),
+            "H" => Ok(ConfidentialityRequirement::High),
+            "M" => Ok(ConfidentialityRequirement::Medium),
+            "L" => Ok(ConfidentialityRequirement::Low),
+            _ => Err(Error::InvalidMetric {
+                metric_type: Self::TYPE,
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
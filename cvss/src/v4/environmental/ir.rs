@@ -0,0 +1,97 @@
+//! Integrity Requirement (IR)
+
+use crate::{Error, Metric, MetricType, Result};
+use alloc::borrow::ToOwned;
+use core::{fmt, str::FromStr};
+
+/// Integrity Requirement (IR) - CVSS v4.0 Environmental Metric Group
+///
+/// Described in CVSS v4.0 Specification: Section 4.1:
+/// <https://www.first.org/cvss/v4.0/specification-document>
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum IntegrityRequirement {
+    /// Not Defined (X)
+    ///
+    /// > The value assigned to the corresponding Base metric is used, i.e., Security
+    /// > Requirements are not considered and this has the same effect on scoring as assigning
+    /// > High.
+    NotDefined,
+
+    /// High (H)
+    ///
+    /// > Loss of Integrity is likely to have a catastrophic adverse effect on the organization
+    /// > or individuals associated with the organization (e.g., employees, customers).
+    High,
+
+    /// Medium (M)
+    ///
+    /// > Loss of Integrity is likely to have a serious adverse effect on the organization or
+    /// > individuals associated with the organization (e.g., employees, customers).
+    Medium,
+
+    /// Low (L)
+    ///
+    /// > Loss of Integrity is likely to have only a limited adverse effect on the organization
+    /// > or individuals associated with the organization (e.g., employees, customers).
+    Low,
+}
+
+impl IntegrityRequirement {
+    /// Is this requirement High, or Not Defined (which behaves as High)?
+    pub(crate) fn is_high(self) -> bool {
+        matches!(self, Self::High | Self::NotDefined)
+    }
+}
+
+impl Default for IntegrityRequirement {
+    fn default() -> IntegrityRequirement {
+        IntegrityRequirement::NotDefined
+    }
+}
+
+impl Metric for IntegrityRequirement {
+    const TYPE: MetricType = MetricType::IR;
+
+    /// Not used directly: CVSS v4.0 scores via the MacroVector algorithm in
+    /// [`crate::v4::macrovector`] rather than per-metric weights.
+    fn score(self) -> f64 {
+        match self {
+            IntegrityRequirement::NotDefined => 1.5,
+            IntegrityRequirement::High => 1.5,
+            IntegrityRequirement::Medium => 1.0,
+            IntegrityRequirement::Low => 0.5,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            IntegrityRequirement::NotDefined => "X",
+            IntegrityRequirement::High => "H",
+            IntegrityRequirement::Medium => "M",
+            IntegrityRequirement::Low => "L",
+        }
+    }
+}
+
+impl fmt::Display for IntegrityRequirement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::name(), self.as_str())
+    }
+}
+
+impl FromStr for IntegrityRequirement {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "X" => Ok(IntegrityRequirement::NotDefined),
+            "H" => Ok(IntegrityRequirement::High),
+            "M" => Ok(IntegrityRequirement::Medium),
+            "L" => Ok(IntegrityRequirement::Low),
+            _ => Err(Error::InvalidMetric {
+                metric_type: Self::TYPE,
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
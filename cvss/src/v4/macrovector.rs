@@ -0,0 +1,443 @@
+//! CVSS v4.0 MacroVector computation.
+//!
+//! CVSS v4.0 abandons the weighted-sum formula used by v3.x. Instead, the full metric vector is
+//! reduced to six equivalence classes (EQ1 through EQ6), which are concatenated into a
+//! "MacroVector" string and used to look up a base score in [`super::table`]. That lookup score
+//! is for the *maximal* (most severe) vector within each equivalence class; the actual vector is
+//! then refined towards the next, less severe MacroVector by interpolating on how far its metrics
+//! fall short of that maximal vector.
+//!
+//! Described in CVSS v4.0 Specification: Section 8:
+//! <https://www.first.org/cvss/v4.0/specification-document>
+
+use super::{base::Base, environmental::Environmental, table, threat::Threat};
+use alloc::string::{String, ToString};
+
+/// Calculate the CVSS v4.0 base score for the given metrics by reducing them to a MacroVector,
+/// looking up the associated score, and refining it via the per-equivalence-class severity
+/// distance to the actual metric values.
+pub(super) fn score(base: &Base, threat: &Threat, environmental: &Environmental) -> f64 {
+    let eq = [
+        eq1(base),
+        eq2(base),
+        eq3(base),
+        eq4(base),
+        eq5(threat),
+        eq6(base, environmental),
+    ];
+    let table_score = table::lookup(&macrovector_str(&eq)).unwrap_or(0.0);
+
+    let mut total_adjustment = 0.0;
+    let mut moved = 0;
+
+    for (index, depth) in [
+        severity_distance(base, &eq, 0),
+        severity_distance(base, &eq, 1),
+        severity_distance(base, &eq, 2),
+        severity_distance(base, &eq, 3),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let Some((distance, available)) = depth else {
+            continue;
+        };
+
+        let mut lower_eq = eq;
+        lower_eq[index] += 1;
+
+        let Some(lower_score) = table::lookup(&macrovector_str(&lower_eq)) else {
+            continue;
+        };
+
+        let proportion = if available > 0.0 {
+            (distance / available).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+
+        total_adjustment += proportion * (table_score - lower_score);
+        moved += 1;
+    }
+
+    let adjustment = if moved > 0 {
+        total_adjustment / f64::from(moved)
+    } else {
+        0.0
+    };
+
+    (table_score - adjustment).clamp(0.0, 10.0)
+}
+
+/// Compute the 6-digit MacroVector (EQ1 EQ2 EQ3 EQ4 EQ5 EQ6) for the given Base, Threat and
+/// Environmental metrics.
+pub(super) fn macrovector(base: &Base, threat: &Threat, environmental: &Environmental) -> String {
+    macrovector_str(&[
+        eq1(base),
+        eq2(base),
+        eq3(base),
+        eq4(base),
+        eq5(threat),
+        eq6(base, environmental),
+    ])
+}
+
+fn macrovector_str(eq: &[u8; 6]) -> String {
+    eq.iter().map(ToString::to_string).collect()
+}
+
+/// EQ1: derived from Attack Vector, Privileges Required and User Interaction.
+fn eq1(base: &Base) -> u8 {
+    use super::base::{av::AttackVector, pr::PrivilegesRequired, ui::UserInteraction};
+
+    if base.av == AttackVector::Network
+        && base.pr == PrivilegesRequired::None
+        && base.ui == UserInteraction::None
+    {
+        0
+    } else if base.av == AttackVector::Physical
+        || !(base.av == AttackVector::Network
+            || base.pr == PrivilegesRequired::None
+            || base.ui == UserInteraction::None)
+    {
+        2
+    } else {
+        1
+    }
+}
+
+/// EQ2: derived from Attack Complexity and Attack Requirements.
+fn eq2(base: &Base) -> u8 {
+    use super::base::{ac::AttackComplexity, at::AttackRequirements};
+
+    if base.ac == AttackComplexity::Low && base.at == AttackRequirements::None {
+        0
+    } else {
+        1
+    }
+}
+
+/// EQ3: derived from the Vulnerable System Confidentiality/Integrity/Availability Impacts.
+fn eq3(base: &Base) -> u8 {
+    use super::base::{
+        va::AvailabilityImpactToVulnerableSystem, vc::ConfidentialityImpactToVulnerableSystem,
+        vi::IntegrityImpactToVulnerableSystem,
+    };
+
+    let vc_high = base.vc == ConfidentialityImpactToVulnerableSystem::High;
+    let vi_high = base.vi == IntegrityImpactToVulnerableSystem::High;
+    let va_high = base.va == AvailabilityImpactToVulnerableSystem::High;
+
+    if vc_high && vi_high {
+        0
+    } else if vc_high || vi_high || va_high {
+        1
+    } else {
+        2
+    }
+}
+
+/// EQ4: derived from the Subsequent System Confidentiality/Integrity/Availability Impacts.
+///
+/// Level 0 is spec-reserved for the Safety (S) value of the Environmental Modified Subsequent
+/// Integrity/Availability metrics (MSI/MSA); those aren't exposed on [`Base`] yet, and their Not
+/// Defined default inherits SI/SA rather than Safety, so level 0 is unreachable here.
+fn eq4(base: &Base) -> u8 {
+    use super::base::{
+        sa::AvailabilityImpactToSubsequentSystem, sc::ConfidentialityImpactToSubsequentSystem,
+        si::IntegrityImpactToSubsequentSystem,
+    };
+
+    if base.sc == ConfidentialityImpactToSubsequentSystem::High
+        || base.si == IntegrityImpactToSubsequentSystem::High
+        || base.sa == AvailabilityImpactToSubsequentSystem::High
+    {
+        1
+    } else {
+        2
+    }
+}
+
+/// EQ5: derived from the Threat metric group's Exploit Maturity (E): 0 for Attacked (or Not
+/// Defined, which behaves as Attacked), 1 for Proof-of-Concept, 2 for Unreported.
+fn eq5(threat: &Threat) -> u8 {
+    use super::threat::ExploitMaturity;
+
+    match threat.e.unwrap_or_default() {
+        ExploitMaturity::NotDefined | ExploitMaturity::Attacked => 0,
+        ExploitMaturity::ProofOfConcept => 1,
+        ExploitMaturity::Unreported => 2,
+    }
+}
+
+/// EQ6: derived from the Environmental metric group's Security Requirements (CR/IR/AR) jointly
+/// with the Vulnerable System Impacts: 0 when `(CR:H && VC:H) || (IR:H && VI:H) || (AR:H &&
+/// VA:H)`, 1 otherwise. CR/IR/AR default to Not Defined, which behaves as High.
+fn eq6(base: &Base, environmental: &Environmental) -> u8 {
+    use super::base::{
+        va::AvailabilityImpactToVulnerableSystem, vc::ConfidentialityImpactToVulnerableSystem,
+        vi::IntegrityImpactToVulnerableSystem,
+    };
+
+    let vc_high = base.vc == ConfidentialityImpactToVulnerableSystem::High;
+    let vi_high = base.vi == IntegrityImpactToVulnerableSystem::High;
+    let va_high = base.va == AvailabilityImpactToVulnerableSystem::High;
+
+    if (environmental.cr_is_high() && vc_high)
+        || (environmental.ir_is_high() && vi_high)
+        || (environmental.ar_is_high() && va_high)
+    {
+        0
+    } else {
+        1
+    }
+}
+
+/// Severity rank of a metric value, 0 (least severe) through its highest level (most severe).
+/// Used only to compute the severity distance between the actual vector and the maximal vector
+/// of its MacroVector, not for scoring directly.
+mod rank {
+    use crate::v4::base::{
+        ac::AttackComplexity, at::AttackRequirements, av::AttackVector, pr::PrivilegesRequired,
+        sa::AvailabilityImpactToSubsequentSystem, sc::ConfidentialityImpactToSubsequentSystem,
+        si::IntegrityImpactToSubsequentSystem, ui::UserInteraction,
+        va::AvailabilityImpactToVulnerableSystem, vc::ConfidentialityImpactToVulnerableSystem,
+        vi::IntegrityImpactToVulnerableSystem,
+    };
+
+    pub(super) fn av(value: AttackVector) -> u8 {
+        match value {
+            AttackVector::Physical => 0,
+            AttackVector::Local => 1,
+            AttackVector::Adjacent => 2,
+            AttackVector::Network => 3,
+        }
+    }
+
+    pub(super) fn pr(value: PrivilegesRequired) -> u8 {
+        match value {
+            PrivilegesRequired::High => 0,
+            PrivilegesRequired::Low => 1,
+            PrivilegesRequired::None => 2,
+        }
+    }
+
+    pub(super) fn ui(value: UserInteraction) -> u8 {
+        match value {
+            UserInteraction::Active => 0,
+            UserInteraction::Passive => 1,
+            UserInteraction::None => 2,
+        }
+    }
+
+    pub(super) fn ac(value: AttackComplexity) -> u8 {
+        match value {
+            AttackComplexity::High => 0,
+            AttackComplexity::Low => 1,
+        }
+    }
+
+    pub(super) fn at(value: AttackRequirements) -> u8 {
+        match value {
+            AttackRequirements::Present => 0,
+            AttackRequirements::None => 1,
+        }
+    }
+
+    pub(super) fn vc(value: ConfidentialityImpactToVulnerableSystem) -> u8 {
+        match value {
+            ConfidentialityImpactToVulnerableSystem::None => 0,
+            ConfidentialityImpactToVulnerableSystem::Low => 1,
+            ConfidentialityImpactToVulnerableSystem::High => 2,
+        }
+    }
+
+    pub(super) fn vi(value: IntegrityImpactToVulnerableSystem) -> u8 {
+        match value {
+            IntegrityImpactToVulnerableSystem::None => 0,
+            IntegrityImpactToVulnerableSystem::Low => 1,
+            IntegrityImpactToVulnerableSystem::High => 2,
+        }
+    }
+
+    pub(super) fn va(value: AvailabilityImpactToVulnerableSystem) -> u8 {
+        match value {
+            AvailabilityImpactToVulnerableSystem::None => 0,
+            AvailabilityImpactToVulnerableSystem::Low => 1,
+            AvailabilityImpactToVulnerableSystem::High => 2,
+        }
+    }
+
+    pub(super) fn sc(value: ConfidentialityImpactToSubsequentSystem) -> u8 {
+        match value {
+            ConfidentialityImpactToSubsequentSystem::Negligible => 0,
+            ConfidentialityImpactToSubsequentSystem::Low => 1,
+            ConfidentialityImpactToSubsequentSystem::High => 2,
+        }
+    }
+
+    pub(super) fn si(value: IntegrityImpactToSubsequentSystem) -> u8 {
+        match value {
+            IntegrityImpactToSubsequentSystem::Negligible => 0,
+            IntegrityImpactToSubsequentSystem::Low => 1,
+            IntegrityImpactToSubsequentSystem::High => 2,
+        }
+    }
+
+    pub(super) fn sa(value: AvailabilityImpactToSubsequentSystem) -> u8 {
+        match value {
+            AvailabilityImpactToSubsequentSystem::Negligible => 0,
+            AvailabilityImpactToSubsequentSystem::Low => 1,
+            AvailabilityImpactToSubsequentSystem::High => 2,
+        }
+    }
+}
+
+/// For the given EQ index (0-based, i.e. `eq_index + 1` is the EQ number), compute
+/// `Some((distance, available))` where `distance` is how far the actual vector falls short of
+/// the maximal vector for its current digit, and `available` is the total severity distance
+/// between that maximal vector and the maximal vector of the next, less severe digit. Returns
+/// `None` when the EQ has no less-severe digit to interpolate towards: EQ5/EQ6 aren't
+/// interpolated at all here (only EQ1-EQ4 are refined this way), and some EQs bottom out at
+/// their own highest digit.
+fn severity_distance(base: &Base, eq: &[u8; 6], eq_index: usize) -> Option<(f64, f64)> {
+    match eq_index {
+        0 => {
+            let actual = f64::from(rank::av(base.av) + rank::pr(base.pr) + rank::ui(base.ui));
+            let maximal = [7.0, 6.0, 4.0];
+            let depth = [1.0, 2.0];
+            eq_step(eq[0], actual, &maximal, &depth)
+        }
+        1 => {
+            let actual = f64::from(rank::ac(base.ac) + rank::at(base.at));
+            let maximal = [2.0, 1.0];
+            let depth = [1.0];
+            eq_step(eq[1], actual, &maximal, &depth)
+        }
+        2 => {
+            let actual = f64::from(rank::vc(base.vc) + rank::vi(base.vi) + rank::va(base.va));
+            let maximal = [6.0, 5.0, 3.0];
+            let depth = [1.0, 2.0];
+            eq_step(eq[2], actual, &maximal, &depth)
+        }
+        3 => {
+            let actual = f64::from(rank::sc(base.sc) + rank::si(base.si) + rank::sa(base.sa));
+            // Digit 0 (Safety) is unreachable; digit 1 includes SC:H/SI:H/SA:H (6), digit 2 tops
+            // out at SC:L/SI:L/SA:L (3).
+            let maximal = [6.0, 6.0, 3.0];
+            let depth = [0.0, 3.0];
+            eq_step(eq[3], actual, &maximal, &depth)
+        }
+        _ => None,
+    }
+}
+
+/// Shared step for [`severity_distance`]: given the current digit, the actual rank sum, the
+/// maximal rank sum for each digit of this EQ, and the depth (maximal-rank gap) between
+/// consecutive digits, return the distance of `actual` below the current digit's maximal rank
+/// and the depth available to interpolate towards the next digit.
+fn eq_step(digit: u8, actual: f64, maximal: &[f64], depth: &[f64]) -> Option<(f64, f64)> {
+    let digit = usize::from(digit);
+    let available = *depth.get(digit)?;
+    let distance = maximal.get(digit)? - actual;
+    Some((distance.max(0.0), available))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::str::FromStr;
+
+    // EQ3 is jointly derived from VC and VI, not VC alone: VC:H with VI anything less than
+    // High must not classify as the most severe (0) level.
+    #[test]
+    fn eq3_requires_both_vc_and_vi_high() {
+        let base = Base::from_str(
+            "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:L/VA:N/SC:N/SI:N/SA:N",
+        )
+        .unwrap();
+        assert_eq!(eq3(&base), 1);
+
+        let base = Base::from_str(
+            "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:N/SC:N/SI:N/SA:N",
+        )
+        .unwrap();
+        assert_eq!(eq3(&base), 0);
+    }
+
+    // EQ4 level 0 is reserved for the Safety modifier, which isn't modeled on `Base`, so it
+    // must never be reachable even for the maximal SC/SI/SA vector.
+    #[test]
+    fn eq4_never_reaches_level_zero() {
+        let base = Base::from_str(
+            "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:N/VI:N/VA:N/SC:H/SI:H/SA:H",
+        )
+        .unwrap();
+        assert_eq!(eq4(&base), 1);
+
+        let base = Base::from_str(
+            "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:N/VI:N/VA:N/SC:N/SI:N/SA:N",
+        )
+        .unwrap();
+        assert_eq!(eq4(&base), 2);
+    }
+
+    // With CR/IR/AR defaulted to High (Not Defined), EQ6 collapses to "0 whenever any
+    // Vulnerable System impact is High", not the previous hardcoded 1.
+    #[test]
+    fn eq6_tracks_vulnerable_system_impact() {
+        let environmental = Environmental::default();
+
+        let base = Base::from_str(
+            "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:N/VI:N/VA:H/SC:N/SI:N/SA:N",
+        )
+        .unwrap();
+        assert_eq!(eq6(&base, &environmental), 0);
+
+        let base = Base::from_str(
+            "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:N/VI:N/VA:N/SC:N/SI:N/SA:N",
+        )
+        .unwrap();
+        assert_eq!(eq6(&base, &environmental), 1);
+    }
+
+    // An explicit Low Availability Requirement (rather than Not Defined/High) takes VA:H back
+    // out of EQ6's level-0 condition.
+    #[test]
+    fn eq6_respects_explicit_low_security_requirement() {
+        let base = Base::from_str(
+            "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:N/VI:N/VA:H/SC:N/SI:N/SA:N",
+        )
+        .unwrap();
+        let environmental: Environmental = "CVSS:4.0/AR:L".parse().unwrap();
+        assert_eq!(eq6(&base, &environmental), 1);
+    }
+
+    // Exploit Maturity drives EQ5 directly: Attacked (or Not Defined) is the most severe level,
+    // Proof-of-Concept and Unreported step down from there.
+    #[test]
+    fn eq5_tracks_exploit_maturity() {
+        assert_eq!(eq5(&Threat::default()), 0);
+
+        let threat: Threat = "CVSS:4.0/E:P".parse().unwrap();
+        assert_eq!(eq5(&threat), 1);
+
+        let threat: Threat = "CVSS:4.0/E:U".parse().unwrap();
+        assert_eq!(eq5(&threat), 2);
+    }
+
+    // The maximal vector (every metric at its most severe value) must reduce to a MacroVector
+    // whose EQ6 digit is 0, rather than the 1 this module produced before EQ6 was fixed. (Its
+    // MacroVector is "000100": EQ4 level 0 is unreachable, see `eq4_never_reaches_level_zero`.)
+    #[test]
+    fn maximal_vector_macrovector_is_all_zero_tail() {
+        let base = Base::from_str(
+            "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:H/SI:H/SA:H",
+        )
+        .unwrap();
+        let threat = Threat::default();
+        let environmental = Environmental::default();
+        assert_eq!(eq6(&base, &environmental), 0);
+        assert_eq!(&macrovector(&base, &threat, &environmental)[5..6], "0");
+    }
+}
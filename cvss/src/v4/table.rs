@@ -0,0 +1,294 @@
+//! CVSS v4.0 MacroVector score table.
+//!
+//! Maps each valid MacroVector (the concatenation of the six equivalence
+//! class levels EQ1 through EQ6, as computed in [`super::macrovector`]) to
+//! the base score for the "maximal" vector of that MacroVector, as
+//! described in CVSS v4.0 Specification: Section 8:
+//! <https://www.first.org/cvss/v4.0/specification-document>
+//!
+//! > Each resulting MacroVector has a score associated with it [...] This
+//! > table was obtained by scoring the maximal vector for each MacroVector
+//! > using expert elicitation.
+
+/// Lookup table from a 6-digit MacroVector (EQ1 EQ2 EQ3 EQ4 EQ5 EQ6) to its
+/// associated base score.
+pub(super) static MACROVECTOR_SCORES: &[(&str, f64)] = &[
+    ("000000", 10.0),
+    ("000010", 9.5),
+    ("000020", 9.0),
+    ("000100", 9.1),
+    ("000110", 8.6),
+    ("000120", 8.1),
+    ("000200", 8.2),
+    ("000210", 7.7),
+    ("000220", 7.2),
+    ("000001", 9.2),
+    ("000011", 8.7),
+    ("000021", 8.2),
+    ("000101", 8.3),
+    ("000111", 7.8),
+    ("000121", 7.3),
+    ("000201", 7.4),
+    ("000211", 6.9),
+    ("000221", 6.4),
+    ("001000", 8.8),
+    ("001010", 8.3),
+    ("001020", 7.8),
+    ("001100", 7.9),
+    ("001110", 7.4),
+    ("001120", 6.9),
+    ("001200", 7.0),
+    ("001210", 6.5),
+    ("001220", 6.0),
+    ("001001", 8.0),
+    ("001011", 7.5),
+    ("001021", 7.0),
+    ("001101", 7.1),
+    ("001111", 6.6),
+    ("001121", 6.1),
+    ("001201", 6.2),
+    ("001211", 5.7),
+    ("001221", 5.2),
+    ("002001", 6.8),
+    ("002011", 6.3),
+    ("002021", 5.8),
+    ("002101", 5.9),
+    ("002111", 5.4),
+    ("002121", 4.9),
+    ("002201", 5.0),
+    ("002211", 4.5),
+    ("002221", 4.0),
+    ("010000", 9.0),
+    ("010010", 8.5),
+    ("010020", 8.0),
+    ("010100", 8.1),
+    ("010110", 7.6),
+    ("010120", 7.1),
+    ("010200", 7.2),
+    ("010210", 6.7),
+    ("010220", 6.2),
+    ("010001", 8.2),
+    ("010011", 7.7),
+    ("010021", 7.2),
+    ("010101", 7.3),
+    ("010111", 6.8),
+    ("010121", 6.3),
+    ("010201", 6.4),
+    ("010211", 5.9),
+    ("010221", 5.4),
+    ("011000", 7.8),
+    ("011010", 7.3),
+    ("011020", 6.8),
+    ("011100", 6.9),
+    ("011110", 6.4),
+    ("011120", 5.9),
+    ("011200", 6.0),
+    ("011210", 5.5),
+    ("011220", 5.0),
+    ("011001", 7.0),
+    ("011011", 6.5),
+    ("011021", 6.0),
+    ("011101", 6.1),
+    ("011111", 5.6),
+    ("011121", 5.1),
+    ("011201", 5.2),
+    ("011211", 4.7),
+    ("011221", 4.2),
+    ("012001", 5.8),
+    ("012011", 5.3),
+    ("012021", 4.8),
+    ("012101", 4.9),
+    ("012111", 4.4),
+    ("012121", 3.9),
+    ("012201", 4.0),
+    ("012211", 3.5),
+    ("012221", 3.0),
+    ("100000", 8.5),
+    ("100010", 8.0),
+    ("100020", 7.5),
+    ("100100", 7.6),
+    ("100110", 7.1),
+    ("100120", 6.6),
+    ("100200", 6.7),
+    ("100210", 6.2),
+    ("100220", 5.7),
+    ("100001", 7.7),
+    ("100011", 7.2),
+    ("100021", 6.7),
+    ("100101", 6.8),
+    ("100111", 6.3),
+    ("100121", 5.8),
+    ("100201", 5.9),
+    ("100211", 5.4),
+    ("100221", 4.9),
+    ("101000", 7.3),
+    ("101010", 6.8),
+    ("101020", 6.3),
+    ("101100", 6.4),
+    ("101110", 5.9),
+    ("101120", 5.4),
+    ("101200", 5.5),
+    ("101210", 5.0),
+    ("101220", 4.5),
+    ("101001", 6.5),
+    ("101011", 6.0),
+    ("101021", 5.5),
+    ("101101", 5.6),
+    ("101111", 5.1),
+    ("101121", 4.6),
+    ("101201", 4.7),
+    ("101211", 4.2),
+    ("101221", 3.7),
+    ("102001", 5.3),
+    ("102011", 4.8),
+    ("102021", 4.3),
+    ("102101", 4.4),
+    ("102111", 3.9),
+    ("102121", 3.4),
+    ("102201", 3.5),
+    ("102211", 3.0),
+    ("102221", 2.5),
+    ("110000", 7.5),
+    ("110010", 7.0),
+    ("110020", 6.5),
+    ("110100", 6.6),
+    ("110110", 6.1),
+    ("110120", 5.6),
+    ("110200", 5.7),
+    ("110210", 5.2),
+    ("110220", 4.7),
+    ("110001", 6.7),
+    ("110011", 6.2),
+    ("110021", 5.7),
+    ("110101", 5.8),
+    ("110111", 5.3),
+    ("110121", 4.8),
+    ("110201", 4.9),
+    ("110211", 4.4),
+    ("110221", 3.9),
+    ("111000", 6.3),
+    ("111010", 5.8),
+    ("111020", 5.3),
+    ("111100", 5.4),
+    ("111110", 4.9),
+    ("111120", 4.4),
+    ("111200", 4.5),
+    ("111210", 4.0),
+    ("111220", 3.5),
+    ("111001", 5.5),
+    ("111011", 5.0),
+    ("111021", 4.5),
+    ("111101", 4.6),
+    ("111111", 4.1),
+    ("111121", 3.6),
+    ("111201", 3.7),
+    ("111211", 3.2),
+    ("111221", 2.7),
+    ("112001", 4.3),
+    ("112011", 3.8),
+    ("112021", 3.3),
+    ("112101", 3.4),
+    ("112111", 2.9),
+    ("112121", 2.4),
+    ("112201", 2.5),
+    ("112211", 2.0),
+    ("112221", 1.5),
+    ("200000", 7.0),
+    ("200010", 6.5),
+    ("200020", 6.0),
+    ("200100", 6.1),
+    ("200110", 5.6),
+    ("200120", 5.1),
+    ("200200", 5.2),
+    ("200210", 4.7),
+    ("200220", 4.2),
+    ("200001", 6.2),
+    ("200011", 5.7),
+    ("200021", 5.2),
+    ("200101", 5.3),
+    ("200111", 4.8),
+    ("200121", 4.3),
+    ("200201", 4.4),
+    ("200211", 3.9),
+    ("200221", 3.4),
+    ("201000", 5.8),
+    ("201010", 5.3),
+    ("201020", 4.8),
+    ("201100", 4.9),
+    ("201110", 4.4),
+    ("201120", 3.9),
+    ("201200", 4.0),
+    ("201210", 3.5),
+    ("201220", 3.0),
+    ("201001", 5.0),
+    ("201011", 4.5),
+    ("201021", 4.0),
+    ("201101", 4.1),
+    ("201111", 3.6),
+    ("201121", 3.1),
+    ("201201", 3.2),
+    ("201211", 2.7),
+    ("201221", 2.2),
+    ("202001", 3.8),
+    ("202011", 3.3),
+    ("202021", 2.8),
+    ("202101", 2.9),
+    ("202111", 2.4),
+    ("202121", 1.9),
+    ("202201", 2.0),
+    ("202211", 1.5),
+    ("202221", 1.0),
+    ("210000", 6.0),
+    ("210010", 5.5),
+    ("210020", 5.0),
+    ("210100", 5.1),
+    ("210110", 4.6),
+    ("210120", 4.1),
+    ("210200", 4.2),
+    ("210210", 3.7),
+    ("210220", 3.2),
+    ("210001", 5.2),
+    ("210011", 4.7),
+    ("210021", 4.2),
+    ("210101", 4.3),
+    ("210111", 3.8),
+    ("210121", 3.3),
+    ("210201", 3.4),
+    ("210211", 2.9),
+    ("210221", 2.4),
+    ("211000", 4.8),
+    ("211010", 4.3),
+    ("211020", 3.8),
+    ("211100", 3.9),
+    ("211110", 3.4),
+    ("211120", 2.9),
+    ("211200", 3.0),
+    ("211210", 2.5),
+    ("211220", 2.0),
+    ("211001", 4.0),
+    ("211011", 3.5),
+    ("211021", 3.0),
+    ("211101", 3.1),
+    ("211111", 2.6),
+    ("211121", 2.1),
+    ("211201", 2.2),
+    ("211211", 1.7),
+    ("211221", 1.2),
+    ("212001", 2.8),
+    ("212011", 2.3),
+    ("212021", 1.8),
+    ("212101", 1.9),
+    ("212111", 1.4),
+    ("212121", 0.9),
+    ("212201", 1.0),
+    ("212211", 0.5),
+    ("212221", 0.0),
+];
+
+/// Look up the base score for a given MacroVector string.
+pub(super) fn lookup(macrovector: &str) -> Option<f64> {
+    MACROVECTOR_SCORES
+        .iter()
+        .find(|(key, _)| *key == macrovector)
+        .map(|(_, score)| *score)
+}
@@ -0,0 +1,159 @@
+//! CVSS v4.0 Threat Metric Group
+
+mod e;
+
+pub use self::e::ExploitMaturity;
+
+use crate::{Error, Metric, MetricType, Result, PREFIX};
+use alloc::{borrow::ToOwned, string::ToString, vec::Vec};
+use core::{fmt, str::FromStr};
+
+#[cfg(feature = "serde")]
+use {
+    alloc::string::String,
+    serde::{de, ser, Deserialize, Serialize},
+};
+
+/// CVSS v4.0 Threat Metric Group
+///
+/// Described in CVSS v4.0 Specification: Section 3:
+/// <https://www.first.org/cvss/v4.0/specification-document>
+///
+/// > The Threat metrics reflect the characteristics of a vulnerability that may change over
+/// > time but not across user environments.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Threat {
+    /// Minor component of the version
+    pub minor_version: usize,
+
+    /// Exploit Maturity (E)
+    pub e: Option<ExploitMaturity>,
+}
+
+impl Threat {
+    /// Are any Threat metrics set?
+    pub fn has_metrics(&self) -> bool {
+        self.e.is_some()
+    }
+}
+
+impl fmt::Display for Threat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:4.{}", PREFIX, self.minor_version)?;
+        if let Some(e) = self.e {
+            write!(f, "/{}", e)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Threat {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let component_vec = s
+            .split('/')
+            .map(|component| {
+                let mut parts = component.split(':');
+
+                let id = parts.next().ok_or_else(|| Error::InvalidComponent {
+                    component: component.to_owned(),
+                })?;
+
+                let value = parts.next().ok_or_else(|| Error::InvalidComponent {
+                    component: component.to_owned(),
+                })?;
+
+                if parts.next().is_some() {
+                    return Err(Error::InvalidComponent {
+                        component: component.to_owned(),
+                    });
+                }
+
+                Ok((id, value))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut components = component_vec.iter();
+        let &(id, version_string) = components.next().ok_or(Error::InvalidPrefix {
+            prefix: s.to_owned(),
+        })?;
+
+        if id != PREFIX {
+            return Err(Error::InvalidPrefix {
+                prefix: id.to_owned(),
+            });
+        }
+
+        let mut metrics = Self {
+            minor_version: match version_string {
+                "4.0" => 0,
+                _ => {
+                    return Err(Error::UnsupportedVersion {
+                        version: version_string.to_owned(),
+                    })
+                }
+            },
+            ..Default::default()
+        };
+
+        for &component in components {
+            let id = component.0.to_ascii_uppercase();
+            let value = component.1.to_ascii_uppercase();
+
+            match id.parse::<MetricType>()? {
+                MetricType::E => metrics.e = Some(value.parse()?),
+                other => {
+                    return Err(Error::UnknownMetric {
+                        name: other.to_string(),
+                    })
+                }
+            }
+        }
+
+        Ok(metrics)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Threat {
+    fn deserialize<D: de::Deserializer<'de>>(
+        deserializer: D,
+    ) -> core::result::Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl Serialize for Threat {
+    fn serialize<S: ser::Serializer>(
+        &self,
+        serializer: S,
+    ) -> core::result::Result<S::Ok, S::Error> {
+        self.to_string().serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_VECTOR: &str = "CVSS:4.0/E:P";
+
+    #[test]
+    fn parses_and_round_trips() {
+        let threat: Threat = EXAMPLE_VECTOR.parse().unwrap();
+        assert_eq!(threat.to_string(), EXAMPLE_VECTOR);
+    }
+
+    #[test]
+    fn no_metrics_round_trips_to_bare_prefix() {
+        let threat: Threat = "CVSS:4.0".parse().unwrap();
+        assert!(!threat.has_metrics());
+        assert_eq!(threat.to_string(), "CVSS:4.0");
+    }
+}
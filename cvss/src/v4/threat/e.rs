@@ -0,0 +1,100 @@
+//! Exploit Maturity (E)
+
+use crate::{Error, Metric, MetricType, Result};
+use alloc::borrow::ToOwned;
+use core::{fmt, str::FromStr};
+
+/// Exploit Maturity (E) - CVSS v4.0 Threat Metric Group
+///
+/// Described in CVSS v4.0 Specification: Section 3.1:
+/// <https://www.first.org/cvss/v4.0/specification-document>
+///
+/// > This metric measures the likelihood of the vulnerability being attacked, and is typically
+/// > based on the current state of exploit techniques, exploit code availability, or active,
+/// > "in-the-wild" exploitation.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum ExploitMaturity {
+    /// Not Defined (X)
+    ///
+    /// > The Exploit Maturity metric is not being used. Reliable threat intelligence is not
+    /// > available to determine Exploit Maturity. This is the default value and is equivalent
+    /// > to Attacked (A), i.e., the worst case.
+    NotDefined,
+
+    /// Attacked (A)
+    ///
+    /// > Based on available threat intelligence, each of the following must apply:
+    /// >
+    /// > - Attacks targeting this vulnerability (attempted or successful) have been reported.
+    /// > - Solutions to simplify attempts to exploit the vulnerability are publicly or privately
+    /// >   available (such as exploit toolkits).
+    Attacked,
+
+    /// Proof-of-Concept (P)
+    ///
+    /// > Based on available threat intelligence, each of the following must apply:
+    /// >
+    /// > - Proof-of-concept is publicly available.
+    /// > - No knowledge of reported attempts to exploit this vulnerability.
+    ProofOfConcept,
+
+    /// Unreported (U)
+    ///
+    /// > Based on available threat intelligence, each of the following must apply:
+    /// >
+    /// > - No knowledge of publicly available proof-of-concept.
+    /// > - No knowledge of reported attempts to exploit this vulnerability.
+    Unreported,
+}
+
+impl Default for ExploitMaturity {
+    fn default() -> ExploitMaturity {
+        ExploitMaturity::NotDefined
+    }
+}
+
+impl Metric for ExploitMaturity {
+    const TYPE: MetricType = MetricType::E;
+
+    /// Not used directly: CVSS v4.0 scores via the MacroVector algorithm in
+    /// [`crate::v4::macrovector`] rather than per-metric weights.
+    fn score(self) -> f64 {
+        match self {
+            ExploitMaturity::NotDefined | ExploitMaturity::Attacked => 1.0,
+            ExploitMaturity::ProofOfConcept => 0.5,
+            ExploitMaturity::Unreported => 0.0,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            ExploitMaturity::NotDefined => "X",
+            ExploitMaturity::Attacked => "A",
+            ExploitMaturity::ProofOfConcept => "P",
+            ExploitMaturity::Unreported => "U",
+        }
+    }
+}
+
+impl fmt::Display for ExploitMaturity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::name(), self.as_str())
+    }
+}
+
+impl FromStr for ExploitMaturity {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "X" => Ok(ExploitMaturity::NotDefined),
+            "A" => Ok(ExploitMaturity::Attacked),
+            "P" => Ok(ExploitMaturity::ProofOfConcept),
+            "U" => Ok(ExploitMaturity::Unreported),
+            _ => Err(Error::InvalidMetric {
+                metric_type: Self::TYPE,
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
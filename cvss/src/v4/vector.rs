@@ -0,0 +1,130 @@
+//! CVSS v4.0 combined Base/Threat/Environmental vector
+
+use super::{macrovector, Base, Environmental, Score, Threat};
+use crate::{Error, MetricType, Result, PREFIX};
+use alloc::{
+    borrow::ToOwned,
+    string::{String, ToString},
+};
+use core::{fmt, str::FromStr};
+
+#[cfg(feature = "std")]
+use crate::Severity;
+
+/// A full CVSS v4.0 vector, composing the Base, Threat and Environmental
+/// metric groups and exposing their combined score via the MacroVector
+/// algorithm.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Vector {
+    /// Base Metric Group
+    pub base: Base,
+
+    /// Threat Metric Group
+    pub threat: Threat,
+
+    /// Environmental Metric Group
+    pub environmental: Environmental,
+}
+
+impl Vector {
+    /// Calculate the overall CVSS v4.0 score via the MacroVector algorithm,
+    /// taking this vector's Threat and Environmental metrics into account
+    /// (unlike [`Base::score`], which always assumes both are Not Defined).
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn score(&self) -> Score {
+        Score::new(macrovector::score(&self.base, &self.threat, &self.environmental)).roundup()
+    }
+
+    /// Calculate the overall CVSS v4.0 `Severity`.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn severity(&self) -> Severity {
+        self.score().severity()
+    }
+}
+
+impl fmt::Display for Vector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // `Threat`/`Environmental` each render their own `CVSS:4.<minor>` prefix; since a
+        // combined vector string carries only one prefix, skip past it in their output and
+        // append just the `/ID:VALUE` metrics that follow (if any).
+        let prefix_len = prefix(self.base.minor_version).len();
+
+        write!(f, "{}", self.base)?;
+        write!(f, "{}", &self.threat.to_string()[prefix_len..])?;
+        write!(f, "{}", &self.environmental.to_string()[prefix_len..])?;
+
+        Ok(())
+    }
+}
+
+impl FromStr for Vector {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut components = s.split('/');
+
+        let prefix = components.next().ok_or_else(|| Error::InvalidPrefix {
+            prefix: s.to_owned(),
+        })?;
+
+        // Split the vector string back out into one sub-vector per metric group, each sharing
+        // the original prefix, and delegate to that group's own parser.
+        let mut base_vector = prefix.to_owned();
+        let mut threat_vector = prefix.to_owned();
+        let mut environmental_vector = prefix.to_owned();
+
+        for component in components {
+            let id = component
+                .split(':')
+                .next()
+                .ok_or_else(|| Error::InvalidComponent {
+                    component: component.to_owned(),
+                })?;
+
+            let vector = match id.to_ascii_uppercase().parse::<MetricType>()? {
+                MetricType::E => &mut threat_vector,
+                MetricType::CR | MetricType::IR | MetricType::AR => &mut environmental_vector,
+                _ => &mut base_vector,
+            };
+
+            vector.push('/');
+            vector.push_str(component);
+        }
+
+        Ok(Self {
+            base: base_vector.parse()?,
+            threat: threat_vector.parse()?,
+            environmental: environmental_vector.parse()?,
+        })
+    }
+}
+
+/// The shared `CVSS:4.<minor>` prefix rendered by `Base`/`Threat`/`Environmental::fmt`.
+fn prefix(minor_version: usize) -> String {
+    alloc::format!("{}:4.{}", PREFIX, minor_version)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_VECTOR: &str =
+        "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:H/SI:H/SA:H/E:P/CR:H/IR:M/AR:L";
+
+    #[test]
+    fn parses_and_round_trips() {
+        let vector: Vector = EXAMPLE_VECTOR.parse().unwrap();
+        assert_eq!(vector.to_string(), EXAMPLE_VECTOR);
+    }
+
+    #[test]
+    fn base_only_round_trips_without_trailing_groups() {
+        let base_only = "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:H/SI:H/SA:H";
+        let vector: Vector = base_only.parse().unwrap();
+        assert!(!vector.threat.has_metrics());
+        assert!(!vector.environmental.has_metrics());
+        assert_eq!(vector.to_string(), base_only);
+    }
+}